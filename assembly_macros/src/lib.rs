@@ -12,21 +12,127 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
+use std::collections::HashSet;
 use syn::{parse_macro_input, DeriveInput, Token, Type};
 use syn::parse::{Parse, ParseStream, Result};
 use syn::punctuated::Punctuated;
 
+/// Keys a bare `dyn Trait` as `Box<dyn Trait>`, the form trait objects are registered in
+fn box_trait_object(ty: Type) -> Type {
+    match ty {
+        Type::TraitObject(trait_object) => syn::parse_quote!(Box<#trait_object>),
+        ty => ty,
+    }
+}
+
+/// Parses a bracketed type list, rejecting types that appear more than once
+fn parse_type_list(input: ParseStream, attribute: &syn::Ident) -> Result<Vec<Type>> {
+    let content;
+    syn::bracketed!(content in input);
+    let types: Punctuated<Type, Token![,]> = content.parse_terminated(Type::parse, Token![,])?;
+    let types: Vec<Type> = types.into_iter().map(box_trait_object).collect();
+
+    let mut seen = HashSet::new();
+    for ty in types.iter() {
+        let key = quote!(#ty).to_string();
+        if !seen.insert(key.clone()) {
+            return Err(syn::Error::new_spanned(
+                ty,
+                format!("duplicate type `{}` in `{}`", key, attribute),
+            ));
+        }
+    }
+    Ok(types)
+}
+
+/// A provided service, either a bare type or a `(Type, "name")` pair
+struct ProvidedService {
+    ty: Type,
+    name: Option<String>,
+}
+
+impl Parse for ProvidedService {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let ty = box_trait_object(content.parse()?);
+            content.parse::<Token![,]>()?;
+            let name: syn::LitStr = content.parse()?;
+            Ok(ProvidedService {
+                ty,
+                name: Some(name.value()),
+            })
+        } else {
+            Ok(ProvidedService {
+                ty: box_trait_object(input.parse()?),
+                name: None,
+            })
+        }
+    }
+}
+
+/// Parses the bracketed `provides` list, rejecting entries that appear more than once
+///
+/// The same type may be listed more than once under distinct names.
+fn parse_provides_list(input: ParseStream) -> Result<Vec<ProvidedService>> {
+    let content;
+    syn::bracketed!(content in input);
+    let services: Punctuated<ProvidedService, Token![,]> =
+        content.parse_terminated(ProvidedService::parse, Token![,])?;
+
+    let mut seen = HashSet::new();
+    for service in services.iter() {
+        let ty = &service.ty;
+        let key = quote!(#ty).to_string();
+        if !seen.insert((key.clone(), service.name.clone())) {
+            let entry = match &service.name {
+                Some(name) => format!("({}, \"{}\")", key, name),
+                None => key,
+            };
+            return Err(syn::Error::new_spanned(
+                ty,
+                format!("duplicate type `{}` in `provides`", entry),
+            ));
+        }
+    }
+    Ok(services.into_iter().collect())
+}
+
+/// Parses a bracketed list of string literals
+fn parse_string_list(input: ParseStream) -> Result<Vec<String>> {
+    let content;
+    syn::bracketed!(content in input);
+    let literals: Punctuated<syn::LitStr, Token![,]> =
+        content.parse_terminated(|input| input.parse::<syn::LitStr>(), Token![,])?;
+    Ok(literals.iter().map(|literal| literal.value()).collect())
+}
+
 struct ServiceAssemblyArgs {
     name: Option<String>,
-    provides: Vec<Type>,
+    provides: Vec<ProvidedService>,
     requires: Vec<Type>,
+    requires_optional: Vec<Type>,
+    provides_collection: Vec<Type>,
+    requires_collection: Vec<Type>,
+    tags: Vec<String>,
+    overrides: Vec<String>,
+    priority: Option<i32>,
+    init: Option<syn::Path>,
 }
 
 impl Parse for ServiceAssemblyArgs {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut name: Option<String> = None;
-        let mut provides: Vec<Type> = Vec::new();
+        let mut provides: Vec<ProvidedService> = Vec::new();
         let mut requires: Vec<Type> = Vec::new();
+        let mut requires_optional: Vec<Type> = Vec::new();
+        let mut provides_collection: Vec<Type> = Vec::new();
+        let mut requires_collection: Vec<Type> = Vec::new();
+        let mut tags: Vec<String> = Vec::new();
+        let mut overrides: Vec<String> = Vec::new();
+        let mut priority: Option<i32> = None;
+        let mut init: Option<syn::Path> = None;
 
         while !input.is_empty() {
             let ident: syn::Ident = input.parse()?;
@@ -36,17 +142,25 @@ impl Parse for ServiceAssemblyArgs {
                 let lit: syn::LitStr = input.parse()?;
                 name = Some(lit.value());
             } else if ident == "provides" {
-                let content;
-                syn::bracketed!(content in input);
-                let types: Punctuated<Type, Token![,]> =
-                    content.parse_terminated(Type::parse, Token![,])?;
-                provides = types.into_iter().collect();
+                provides = parse_provides_list(input)?;
             } else if ident == "requires" {
-                let content;
-                syn::bracketed!(content in input);
-                let types: Punctuated<Type, Token![,]> =
-                    content.parse_terminated(Type::parse, Token![,])?;
-                requires = types.into_iter().collect();
+                requires = parse_type_list(input, &ident)?;
+            } else if ident == "requires_optional" {
+                requires_optional = parse_type_list(input, &ident)?;
+            } else if ident == "provides_collection" {
+                provides_collection = parse_type_list(input, &ident)?;
+            } else if ident == "requires_collection" {
+                requires_collection = parse_type_list(input, &ident)?;
+            } else if ident == "tags" {
+                tags = parse_string_list(input)?;
+            } else if ident == "overrides" {
+                overrides = parse_string_list(input)?;
+            } else if ident == "priority" {
+                let negative = input.parse::<Option<Token![-]>>()?.is_some();
+                let value: i32 = input.parse::<syn::LitInt>()?.base10_parse()?;
+                priority = Some(if negative { -value } else { value });
+            } else if ident == "init" {
+                init = Some(input.parse()?);
             }
 
             if !input.is_empty() {
@@ -58,6 +172,13 @@ impl Parse for ServiceAssemblyArgs {
             name,
             provides,
             requires,
+            requires_optional,
+            provides_collection,
+            requires_collection,
+            tags,
+            overrides,
+            priority,
+            init,
         })
     }
 }
@@ -66,56 +187,203 @@ impl Parse for ServiceAssemblyArgs {
 pub fn assembly(attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     let args = parse_macro_input!(attr as ServiceAssemblyArgs);
 
-    let assembly_name = args.name.unwrap_or_else(|| struct_name.to_string());
-    let provides_types = args.provides;
+    // Default to the fully-qualified path so identically named structs in different modules
+    // do not collide in the dependency graph
+    let assembly_name = match args.name {
+        Some(name) => quote! { #name },
+        None => {
+            let ident = struct_name.to_string();
+            quote! { concat!(module_path!(), "::", #ident) }
+        }
+    };
+    // Each type is provided once, however many names it is bound under
+    let mut seen_provides = HashSet::new();
+    let provides_types: Vec<&Type> = args
+        .provides
+        .iter()
+        .map(|service| &service.ty)
+        .filter(|ty| seen_provides.insert(quote!(#ty).to_string()))
+        .collect();
     let requires_types = args.requires;
+    let requires_optional_types = args.requires_optional;
 
     // Generate the provides() method
     let provides_impl = if provides_types.is_empty() {
         quote! {
-            fn provides(&self) -> Vec<TypeKey> {
+            fn provides(&self) -> Vec<::assemblr::assembly::TypeKey> {
                 Vec::new()
             }
         }
     } else {
         quote! {
-            fn provides(&self) -> Vec<TypeKey> {
-                vec![#(TypeKey::new::<#provides_types>()),*]
+            fn provides(&self) -> Vec<::assemblr::assembly::TypeKey> {
+                vec![#(::assemblr::assembly::TypeKey::new::<#provides_types>()),*]
+            }
+        }
+    };
+
+    // Generate the provides_named() method when any provided service is named
+    let provides_named_impl = if args.provides.iter().any(|service| service.name.is_some()) {
+        let entries = args.provides.iter().map(|service| {
+            let ty = &service.ty;
+            match &service.name {
+                Some(name) => quote! {
+                    (::assemblr::assembly::TypeKey::new::<#ty>(), Some(String::from(#name)))
+                },
+                None => quote! { (::assemblr::assembly::TypeKey::new::<#ty>(), None) },
+            }
+        });
+        quote! {
+            fn provides_named(&self) -> Vec<(::assemblr::assembly::TypeKey, Option<String>)> {
+                vec![#(#entries),*]
             }
         }
+    } else {
+        quote! {}
     };
 
     // Generate the requires() method
     let requires_impl = if requires_types.is_empty() {
         quote! {
-            fn requires(&self) -> Vec<TypeKey> {
+            fn requires(&self) -> Vec<::assemblr::assembly::TypeKey> {
+                Vec::new()
+            }
+        }
+    } else {
+        quote! {
+            fn requires(&self) -> Vec<::assemblr::assembly::TypeKey> {
+                vec![#(::assemblr::assembly::TypeKey::new::<#requires_types>()),*]
+            }
+        }
+    };
+
+    // Generate the optional_requires() method
+    let optional_requires_impl = if requires_optional_types.is_empty() {
+        quote! {
+            fn optional_requires(&self) -> Vec<::assemblr::assembly::TypeKey> {
                 Vec::new()
             }
         }
     } else {
         quote! {
-            fn requires(&self) -> Vec<TypeKey> {
-                vec![#(TypeKey::new::<#requires_types>()),*]
+            fn optional_requires(&self) -> Vec<::assemblr::assembly::TypeKey> {
+                vec![#(::assemblr::assembly::TypeKey::new::<#requires_optional_types>()),*]
             }
         }
     };
 
+    // Generate the collection methods only when collections are declared
+    let provides_collection_types = args.provides_collection;
+    let provides_collection_impl = if provides_collection_types.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn provides_collection(&self) -> Vec<::assemblr::assembly::TypeKey> {
+                vec![#(::assemblr::assembly::TypeKey::new::<#provides_collection_types>()),*]
+            }
+        }
+    };
+    let requires_collection_types = args.requires_collection;
+    let requires_collection_impl = if requires_collection_types.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn requires_collection(&self) -> Vec<::assemblr::assembly::TypeKey> {
+                vec![#(::assemblr::assembly::TypeKey::new::<#requires_collection_types>()),*]
+            }
+        }
+    };
+
+    // Generate the tags() method when any tag is given
+    let tags = args.tags;
+    let tags_impl = if tags.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn tags(&self) -> Vec<String> {
+                vec![#(String::from(#tags)),*]
+            }
+        }
+    };
+
+    // Generate the overrides() method when any assembly is overridden
+    let overrides = args.overrides;
+    let overrides_impl = if overrides.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn overrides(&self) -> Vec<String> {
+                vec![#(String::from(#overrides)),*]
+            }
+        }
+    };
+
+    // Generate the priority() method when a priority is given
+    let priority_impl = match args.priority {
+        Some(priority) => quote! {
+            fn priority(&self) -> i32 {
+                #priority
+            }
+        },
+        None => quote! {},
+    };
+
+    // List the provided type names so tooling can read them without an instance
+    let provides_const = quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            pub const PROVIDES: &'static [&'static str] = &[#(stringify!(#provides_types)),*];
+        }
+    };
+
+    // Generate the ServiceAssembly impl when an init function is given
+    let service_assembly_impl = match args.init {
+        Some(init_fn) => quote! {
+            impl #impl_generics ::assemblr::assembly::ServiceAssembly for #struct_name #ty_generics #where_clause {
+                fn init(
+                    &self,
+                    context: &::assemblr::assembly::MutableAssemblyContext,
+                ) -> ::assemblr::assembly::Result<()> {
+                    #init_fn(self, context)
+                }
+            }
+        },
+        None => quote! {},
+    };
+
     // Generate the output
     let expanded = quote! {
         #input
 
-        impl ServiceAssemblyBase for #struct_name {
+        impl #impl_generics ::assemblr::assembly::ServiceAssemblyBase for #struct_name #ty_generics #where_clause {
             fn name(&self) -> &str {
                 #assembly_name
             }
 
             #provides_impl
 
+            #provides_named_impl
+
             #requires_impl
+
+            #optional_requires_impl
+
+            #provides_collection_impl
+
+            #requires_collection_impl
+
+            #tags_impl
+            #overrides_impl
+
+            #priority_impl
         }
+
+        #provides_const
+
+        #service_assembly_impl
     };
 
     TokenStream::from(expanded)
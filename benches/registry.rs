@@ -0,0 +1,87 @@
+// Copyright (c) 2025 Metaform Systems, Inc
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Contributors:
+//      Metaform Systems, Inc. - initial API and implementation
+
+//! Read throughput of `ServiceRegistry`
+//!
+//! Compare the default `RwLock` storage with the lock-free storage by saving a baseline:
+//!
+//! ```text
+//! cargo bench --bench registry -- --save-baseline rwlock
+//! cargo bench --bench registry --features arc-swap -- --baseline rwlock
+//! ```
+
+use assemblr::registry::{RegistryWriteHandle, ServiceRegistry};
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct Database {
+    #[allow(dead_code)]
+    url: String,
+}
+
+struct Other<const N: usize>;
+
+const READER_THREADS: usize = 4;
+
+fn populated_registry() -> ServiceRegistry {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    handle.register(Arc::new(Database {
+        url: "postgres://localhost".to_string(),
+    }));
+    handle.register(Arc::new(Other::<0>));
+    handle.register(Arc::new(Other::<1>));
+    handle.register(Arc::new(Other::<2>));
+    handle.register(Arc::new(Other::<3>));
+    registry
+}
+
+fn single_threaded_reads(c: &mut Criterion) {
+    let registry = populated_registry();
+
+    c.bench_function("resolve", |b| b.iter(|| black_box(registry.resolve::<Database>())));
+    c.bench_function("contains", |b| b.iter(|| black_box(registry.contains::<Database>())));
+}
+
+fn contended_reads(c: &mut Criterion) {
+    let registry = Arc::new(populated_registry());
+
+    // Each iteration is one resolve on every reader thread
+    c.bench_function("resolve_contended", |b| {
+        b.iter_custom(|iters| {
+            let start = Instant::now();
+            let readers: Vec<_> = (0..READER_THREADS)
+                .map(|_| {
+                    let registry = Arc::clone(&registry);
+                    thread::spawn(move || {
+                        for _ in 0..iters {
+                            black_box(registry.resolve::<Database>());
+                        }
+                    })
+                })
+                .collect();
+            for reader in readers {
+                reader.join().unwrap();
+            }
+            start.elapsed()
+        })
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().measurement_time(Duration::from_secs(3));
+    targets = single_threaded_reads, contended_reads
+}
+criterion_main!(benches);
@@ -11,15 +11,21 @@
 
 #![allow(dead_code)]
 
-use std::any::TypeId;
-use std::collections::HashMap;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::sync::{Arc, RwLock};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use thiserror::Error;
 
 use crate::dag::Graph;
-use crate::registry::{RegistryWriteHandle, ServiceRegistry};
+pub use crate::type_key::TypeKey;
+use crate::registry::{RegisterObserver, RegistryWriteHandle, ServiceRegistry};
 
 #[derive(Error, Debug)]
 pub enum AssemblyError {
@@ -29,20 +35,130 @@ pub enum AssemblyError {
     #[error("Assembly '{assembly}' error: {message}")]
     MissingDependency { assembly: String, message: String },
 
-    #[error("Cyclic dependency detected in assembly graph ({0})")]
-    CyclicDependency(String),
+    #[error("Required assembly not found for:\n{}", format_missing_dependencies(.0))]
+    MissingDependencies(Vec<(String, TypeKey)>),
+
+    /// `cycle` names the assemblies in the loop; `services` holds, for each step, the service
+    /// the assembly requires from the next one
+    #[error("Cyclic dependency detected in assembly graph ({cycle}){}", format_cycle_services(.services))]
+    CyclicDependency { cycle: String, services: Vec<TypeKey> },
+
+    #[error("Service {type_key} is provided by multiple assemblies: {}", .assemblies.join(", "))]
+    DuplicateProvider {
+        type_key: TypeKey,
+        assemblies: Vec<String>,
+    },
+
+    /// Reported for `requires`, `optional_requires` and `requires_collection` alike
+    #[error("Assembly '{assembly}' both provides and requires {type_key}")]
+    SelfDependency { assembly: String, type_key: TypeKey },
+
+    #[error("Assembly '{assembly}' overrides '{overridden}', but no other assembly with that name is registered")]
+    UnknownOverride { assembly: String, overridden: String },
+
+    #[error("Assembly '{assembly}' declares {type_key} but did not register it during init")]
+    UnfulfilledProvision { assembly: String, type_key: TypeKey },
+
+    #[error("Assembly '{0}' is registered more than once")]
+    DuplicateAssembly(String),
+
+    #[error("Assembly '{assembly}' did not complete {phase} within the phase timeout")]
+    PhaseTimeout { assembly: String, phase: Phase },
+
+    #[error("Assembly '{assembly}' failed during {phase}: {source}")]
+    PhaseFailure {
+        assembly: String,
+        phase: Phase,
+        source: Box<AssemblyError>,
+    },
+
+    #[error("Errors shutting down:\n{}", format_shutdown_errors(.0))]
+    ShutdownErrors(Vec<(String, AssemblyError)>),
+
+    /// A failure that may succeed if retried, e.g. a connection error during `init`
+    ///
+    /// Assemblies wrap errors in this variant to opt in to retries; see `is_transient`.
+    #[error("{0}")]
+    Transient(Box<AssemblyError>),
 
     #[error("{0}")]
     GeneralError(String),
 }
 
+impl AssemblyError {
+    /// Returns whether retrying the operation that failed might succeed
+    ///
+    /// Only errors wrapped in `Transient` are transient, including when reported as the source
+    /// of a `PhaseFailure`. Dependency errors are never transient, even when wrapped, since
+    /// retrying cannot fix the assembly graph.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            AssemblyError::Transient(inner) => !matches!(
+                **inner,
+                AssemblyError::CyclicDependency { .. }
+                    | AssemblyError::MissingDependency { .. }
+                    | AssemblyError::MissingDependencies(_)
+            ),
+            AssemblyError::PhaseFailure { source, .. } => source.is_transient(),
+            _ => false,
+        }
+    }
+}
+
+fn format_shutdown_errors(errors: &[(String, AssemblyError)]) -> String {
+    errors
+        .iter()
+        .map(|(assembly, error)| format!("  {}: {}", assembly, error))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_cycle_services(services: &[TypeKey]) -> String {
+    if services.is_empty() {
+        return String::new();
+    }
+    let services = services.iter().map(TypeKey::to_string).collect::<Vec<_>>();
+    format!(" via services: {}", services.join(" -> "))
+}
+
+fn format_missing_dependencies(missing: &[(String, TypeKey)]) -> String {
+    missing
+        .iter()
+        .map(|(assembly, type_key)| format!("  {} requires {}", assembly, type_key))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 pub type Result<T> = std::result::Result<T, AssemblyError>;
 
+/// Runs a teardown callback, turning a panic into an error so the remaining assemblies still
+/// get torn down
+///
+/// The assembly is only used to report the panic, so observing it after an unwind is safe.
+fn catch_teardown_panic(
+    assembly: &str,
+    phase: Phase,
+    f: impl FnOnce() -> Result<()>,
+) -> Result<()> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let reason = payload
+            .downcast_ref::<&str>()
+            .map(|reason| reason.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(AssemblyError::GeneralError(format!(
+            "'{}' panicked during {}: {}",
+            assembly, phase, reason
+        )))
+    })
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RuntimeMode {
     Debug,
     Development,
     Production,
+    Test,
 }
 
 impl RuntimeMode {
@@ -50,14 +166,56 @@ impl RuntimeMode {
         true
     }
 
+    pub fn is_production(&self) -> bool {
+        *self == RuntimeMode::Production
+    }
+
+    pub fn is_development(&self) -> bool {
+        *self == RuntimeMode::Development
+    }
+
+    pub fn is_debug(&self) -> bool {
+        *self == RuntimeMode::Debug
+    }
+
     pub fn parse(mode: &str) -> Result<Self> {
         match mode.to_lowercase().as_str() {
             "production" | "prod" => Ok(RuntimeMode::Production),
             "development" | "dev" => Ok(RuntimeMode::Development),
             "debug" => Ok(RuntimeMode::Debug),
+            "test" => Ok(RuntimeMode::Test),
             _ => Err(AssemblyError::InvalidRuntimeMode(mode.to_string())),
         }
     }
+
+    /// Parses the mode from the named environment variable
+    ///
+    /// Fails with `InvalidRuntimeMode` if the variable is unset, is not valid unicode or holds an
+    /// unrecognized value.
+    pub fn from_env(var: &str) -> Result<Self> {
+        match std::env::var(var) {
+            Ok(value) => Self::parse(&value),
+            Err(std::env::VarError::NotPresent) => {
+                Err(AssemblyError::InvalidRuntimeMode(format!("{} is not set", var)))
+            }
+            Err(std::env::VarError::NotUnicode(_)) => Err(Self::not_unicode(var)),
+        }
+    }
+
+    /// Parses the mode from the named environment variable, using `default` if it is unset
+    ///
+    /// An unrecognized or non-unicode value is still an error rather than silently falling back.
+    pub fn from_env_or(var: &str, default: RuntimeMode) -> Result<Self> {
+        match std::env::var(var) {
+            Ok(value) => Self::parse(&value),
+            Err(std::env::VarError::NotPresent) => Ok(default),
+            Err(std::env::VarError::NotUnicode(_)) => Err(Self::not_unicode(var)),
+        }
+    }
+
+    fn not_unicode(var: &str) -> AssemblyError {
+        AssemblyError::InvalidRuntimeMode(format!("{} is not valid unicode", var))
+    }
 }
 
 impl fmt::Display for RuntimeMode {
@@ -66,6 +224,7 @@ impl fmt::Display for RuntimeMode {
             RuntimeMode::Debug => write!(f, "debug"),
             RuntimeMode::Development => write!(f, "development"),
             RuntimeMode::Production => write!(f, "production"),
+            RuntimeMode::Test => write!(f, "test"),
         }
     }
 }
@@ -75,6 +234,33 @@ pub trait LogMonitor: Send + Sync {
     fn info(&self, message: &str);
     fn warn(&self, message: &str);
     fn error(&self, message: &str);
+
+    /// Logs a message with structured key-value fields
+    ///
+    /// The default appends the fields to the message, e.g.
+    /// `Initialized: db (assembly=db, phase=init)`, and forwards it to the method for `level`.
+    /// Monitors that support structured output can override this to keep the fields separate.
+    fn event(&self, level: Level, message: &str, fields: &[(&str, &str)]) {
+        let message = format_fields(message, fields);
+        match level {
+            Level::Debug => self.debug(&message),
+            Level::Info => self.info(&message),
+            Level::Warn => self.warn(&message),
+            Level::Error => self.error(&message),
+        }
+    }
+}
+
+fn format_fields(message: &str, fields: &[(&str, &str)]) -> String {
+    if fields.is_empty() {
+        return message.to_string();
+    }
+    let fields = fields
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{} ({})", message, fields)
 }
 
 pub struct NoopMonitor;
@@ -86,26 +272,326 @@ impl LogMonitor for NoopMonitor {
     fn error(&self, _: &str) {}
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
-pub struct TypeKey(TypeId, String);
+/// Severity of a message passed to a `LogMonitor`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    /// Returns the default minimum level for a runtime mode
+    pub fn from_runtime_mode(mode: RuntimeMode) -> Self {
+        match mode {
+            RuntimeMode::Debug | RuntimeMode::Test => Level::Debug,
+            RuntimeMode::Development => Level::Info,
+            RuntimeMode::Production => Level::Warn,
+        }
+    }
+}
+
+/// A monitor that records every message in memory, e.g. for assertions in lifecycle tests
+#[derive(Default)]
+pub struct CapturingMonitor {
+    messages: Mutex<Vec<(Level, String)>>,
+}
+
+impl CapturingMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns all captured messages in the order they were logged
+    pub fn messages(&self) -> Vec<(Level, String)> {
+        self.messages.lock().unwrap().clone()
+    }
+
+    /// Returns the captured messages logged at the given level
+    pub fn messages_at_level(&self, level: Level) -> Vec<String> {
+        self.messages
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(l, _)| *l == level)
+            .map(|(_, message)| message.clone())
+            .collect()
+    }
+
+    fn capture(&self, level: Level, message: &str) {
+        self.messages.lock().unwrap().push((level, message.to_string()));
+    }
+}
+
+impl LogMonitor for CapturingMonitor {
+    fn debug(&self, message: &str) {
+        self.capture(Level::Debug, message);
+    }
+
+    fn info(&self, message: &str) {
+        self.capture(Level::Info, message);
+    }
+
+    fn warn(&self, message: &str) {
+        self.capture(Level::Warn, message);
+    }
+
+    fn error(&self, message: &str) {
+        self.capture(Level::Error, message);
+    }
+}
+
+/// A monitor that prefixes every message with the name of a scope, such as an assembly
+pub struct ScopedMonitor {
+    scope: String,
+    inner: Arc<dyn LogMonitor>,
+}
+
+impl ScopedMonitor {
+    pub fn new(scope: &str, inner: Arc<dyn LogMonitor>) -> Self {
+        ScopedMonitor {
+            scope: scope.to_string(),
+            inner,
+        }
+    }
+}
+
+impl LogMonitor for ScopedMonitor {
+    fn debug(&self, message: &str) {
+        self.inner.debug(&format!("[{}] {}", self.scope, message));
+    }
+
+    fn info(&self, message: &str) {
+        self.inner.info(&format!("[{}] {}", self.scope, message));
+    }
+
+    fn warn(&self, message: &str) {
+        self.inner.warn(&format!("[{}] {}", self.scope, message));
+    }
+
+    fn error(&self, message: &str) {
+        self.inner.error(&format!("[{}] {}", self.scope, message));
+    }
+
+    fn event(&self, level: Level, message: &str, fields: &[(&str, &str)]) {
+        self.inner
+            .event(level, &format!("[{}] {}", self.scope, message), fields);
+    }
+}
+
+/// A monitor that emits every message as a `tracing` event under the `assemblr` target
+#[cfg(feature = "tracing")]
+pub struct TracingMonitor;
+
+#[cfg(feature = "tracing")]
+impl LogMonitor for TracingMonitor {
+    fn debug(&self, message: &str) {
+        ::tracing::debug!(target: "assemblr", "{}", message);
+    }
+
+    fn info(&self, message: &str) {
+        ::tracing::info!(target: "assemblr", "{}", message);
+    }
+
+    fn warn(&self, message: &str) {
+        ::tracing::warn!(target: "assemblr", "{}", message);
+    }
+
+    fn error(&self, message: &str) {
+        ::tracing::error!(target: "assemblr", "{}", message);
+    }
+}
+
+/// A monitor that forwards only messages at or above a minimum level
+pub struct FilteringMonitor {
+    inner: Arc<dyn LogMonitor>,
+    threshold: Level,
+}
+
+impl FilteringMonitor {
+    pub fn new(inner: Arc<dyn LogMonitor>, threshold: Level) -> Self {
+        FilteringMonitor { inner, threshold }
+    }
+}
+
+impl LogMonitor for FilteringMonitor {
+    fn debug(&self, message: &str) {
+        if Level::Debug >= self.threshold {
+            self.inner.debug(message);
+        }
+    }
+
+    fn info(&self, message: &str) {
+        if Level::Info >= self.threshold {
+            self.inner.info(message);
+        }
+    }
+
+    fn warn(&self, message: &str) {
+        if Level::Warn >= self.threshold {
+            self.inner.warn(message);
+        }
+    }
+
+    fn error(&self, message: &str) {
+        if Level::Error >= self.threshold {
+            self.inner.error(message);
+        }
+    }
 
-impl TypeKey {
-    pub fn new<T: 'static>() -> Self {
-        TypeKey(TypeId::of::<T>(), String::from(std::any::type_name::<T>()))
+    fn event(&self, level: Level, message: &str, fields: &[(&str, &str)]) {
+        if level >= self.threshold {
+            self.inner.event(level, message, fields);
+        }
     }
 }
 
-impl fmt::Display for TypeKey {
+/// Builds the monitor handed to an assembly's context from its name and the assembler's monitor
+pub type MonitorBuilder = Arc<dyn Fn(&str, Arc<dyn LogMonitor>) -> Arc<dyn LogMonitor> + Send + Sync>;
+
+/// How `Assembler::register_all` handles an assembly whose name is already registered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Fail with `AssemblyError::DuplicateAssembly` and register nothing from the batch
+    #[default]
+    Reject,
+    /// Replace the earlier assembly with the later one, keeping its registration position
+    Replace,
+}
+
+/// A lifecycle phase of a service assembly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Configure,
+    Init,
+    Prepare,
+    Start,
+    HealthCheck,
+    Finalize,
+    Shutdown,
+}
+
+impl fmt::Display for Phase {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.1)
+        match self {
+            Phase::Configure => write!(f, "configure"),
+            Phase::Init => write!(f, "init"),
+            Phase::Prepare => write!(f, "prepare"),
+            Phase::Start => write!(f, "start"),
+            Phase::HealthCheck => write!(f, "health_check"),
+            Phase::Finalize => write!(f, "finalize"),
+            Phase::Shutdown => write!(f, "shutdown"),
+        }
+    }
+}
+
+/// Receives structured notifications as assemblies move through their lifecycle phases
+pub trait LifecycleObserver: Send + Sync {
+    fn on_phase_start(&self, assembly: &str, phase: Phase);
+    fn on_phase_end(&self, assembly: &str, phase: Phase, result: &Result<()>);
+
+    /// Called during `assemble` as each assembly begins the configure, init, prepare or start phase
+    ///
+    /// `index` counts the assemblies that began the phase before this one, from 0 to
+    /// `total - 1`, where `total` is the number of assemblies being assembled.
+    fn on_progress(&self, _assembly: &str, _phase: Phase, _index: usize, _total: usize) {}
+}
+
+/// Services resolved through `require`, paired with the name of the assembly that resolved them
+pub type ResolutionAudit = Arc<Mutex<Vec<(String, TypeKey)>>>;
+
+/// Records that the assembly resolved the service, if auditing is enabled
+fn record_resolution<T: 'static>(audit: &Option<ResolutionAudit>, assembly: &str) {
+    if let Some(audit) = audit {
+        let entry = (assembly.to_string(), TypeKey::new::<T>());
+        let mut resolved = audit.lock().unwrap();
+        if !resolved.contains(&entry) {
+            resolved.push(entry);
+        }
+    }
+}
+
+/// Which assemblies have completed `init` while assembling, used by `resolve_required` and
+/// `require` to explain early resolutions
+#[derive(Clone)]
+pub struct InitProgress {
+    /// The assembly providing each service, as chosen when resolving the dependency graph
+    providers: Arc<HashMap<TypeKey, String>>,
+    initialized: Arc<Mutex<HashSet<String>>>,
+}
+
+impl InitProgress {
+    fn new(resolution: &Resolution) -> Self {
+        InitProgress {
+            providers: Arc::new(resolution.providers.clone()),
+            initialized: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    fn mark_initialized(&self, assembly: &str) {
+        self.initialized.lock().unwrap().insert(assembly.to_string());
+    }
+
+    /// Explains why the assembly cannot resolve the service yet, if its provider has not
+    /// completed `init`
+    ///
+    /// Providers of services declared in `requires` are always initialized first, so a pending
+    /// provider means the service is missing from the assembly's `requires`.
+    fn explain_pending(&self, assembly: &str, key: &TypeKey) -> Option<String> {
+        let provider = self.providers.get(key)?;
+        if provider == assembly || self.initialized.lock().unwrap().contains(provider) {
+            return None;
+        }
+        Some(format!(
+            "{} is provided by '{}', which has not been initialized yet; add it to the requires of '{}' so '{}' is initialized first",
+            key, provider, assembly, provider
+        ))
     }
 }
 
 /// Context provided during the init() and prepare() phases with write access to the registry
 pub struct MutableAssemblyContext {
+    /// Resolving through the registry directly panics or returns `None` for missing services,
+    /// including those whose provider has not been initialized yet; use `resolve_required` or
+    /// `require` for errors that explain the initialization order
     pub registry: RegistryWriteHandle,
     pub log_monitor: Arc<dyn LogMonitor>,
     pub mode: RuntimeMode,
+    /// Name of the assembly whose lifecycle phase receives the context
+    pub current_assembly: String,
+    /// Where `require` records resolved services; `None` outside Debug and Development mode
+    pub resolution_audit: Option<ResolutionAudit>,
+    /// Which providers have been initialized; set for the init phase of `assemble`
+    pub init_progress: Option<InitProgress>,
+}
+
+impl MutableAssemblyContext {
+    /// Resolves a service without panicking, building it from its factory if necessary
+    ///
+    /// Fails with `MissingDependency` naming this context's assembly if the service is absent or
+    /// its factory fails, so lifecycle hooks can return the error instead of panicking. During
+    /// `init`, a service whose provider has not been initialized yet is reported with a hint on
+    /// fixing the initialization order. This and `require` are the only resolution paths that
+    /// give the hint; `registry.resolve` and `registry.try_resolve` do not.
+    pub fn resolve_required<T: Any + Send + Sync + 'static>(&self) -> Result<Arc<T>> {
+        self.registry.try_resolve_factory::<T>().map_err(|e| {
+            let pending = self.init_progress.as_ref().and_then(|progress| {
+                progress.explain_pending(&self.current_assembly, &TypeKey::new::<T>())
+            });
+            AssemblyError::MissingDependency {
+                assembly: self.current_assembly.clone(),
+                message: pending.unwrap_or_else(|| e.to_string()),
+            }
+        })
+    }
+
+    /// Resolves a service like `resolve_required` and records it for
+    /// `Assembler::undeclared_dependencies`
+    pub fn require<T: Any + Send + Sync + 'static>(&self) -> Result<Arc<T>> {
+        record_resolution::<T>(&self.resolution_audit, &self.current_assembly);
+        self.resolve_required::<T>()
+    }
 }
 
 /// Context provided during the start() phase with read-only registry access
@@ -114,6 +600,27 @@ pub struct AssemblyContext {
     pub registry: Arc<ServiceRegistry>,
     pub log_monitor: Arc<dyn LogMonitor>,
     pub mode: RuntimeMode,
+    /// Name of the assembly whose lifecycle phase receives the context; empty for contexts not
+    /// tied to an assembly, such as the one passed to `Assembler::assemble_then`
+    pub current_assembly: String,
+    /// Where `require` records resolved services; `None` outside Debug and Development mode
+    pub resolution_audit: Option<ResolutionAudit>,
+}
+
+impl AssemblyContext {
+    /// Resolves a service without panicking and records it for `Assembler::undeclared_dependencies`
+    ///
+    /// Fails with `MissingDependency` naming this context's assembly if the service is absent or
+    /// its factory fails.
+    pub fn require<T: Any + Send + Sync + 'static>(&self) -> Result<Arc<T>> {
+        record_resolution::<T>(&self.resolution_audit, &self.current_assembly);
+        self.registry
+            .try_resolve_factory::<T>()
+            .map_err(|e| AssemblyError::MissingDependency {
+                assembly: self.current_assembly.clone(),
+                message: e.to_string(),
+            })
+    }
 }
 
 /// Base trait for service assembly metadata
@@ -124,13 +631,95 @@ pub trait ServiceAssemblyBase: Send + Sync {
         Vec::new()
     }
 
+    /// Provided services paired with the name they are bound under, if any
+    ///
+    /// Defaults to every entry of `provides` without a name.
+    fn provides_named(&self) -> Vec<(TypeKey, Option<String>)> {
+        self.provides().into_iter().map(|key| (key, None)).collect()
+    }
+
     fn requires(&self) -> Vec<TypeKey> {
         Vec::new()
     }
+
+    /// Services used when available; they order initialization only if some assembly provides them
+    ///
+    /// Like `requires`, they must not include the assembly's own `provides`.
+    fn optional_requires(&self) -> Vec<TypeKey> {
+        Vec::new()
+    }
+
+    /// Services the assembly decided to provide while running `init`, e.g. based on configuration
+    ///
+    /// Called once `init` returns and merged with `provides` when reporting what an assembly
+    /// provides. Because they are only known after `init`, dynamic provides do not order
+    /// initialization: assemblies requiring them must list the provider's static services or
+    /// resolve them lazily.
+    fn dynamic_provides(&self) -> Vec<TypeKey> {
+        Vec::new()
+    }
+
+    /// Collections the assembly contributes to; any number of assemblies may contribute
+    fn provides_collection(&self) -> Vec<TypeKey> {
+        Vec::new()
+    }
+
+    /// Collections that need at least one contributor; initialization follows every contributor
+    ///
+    /// The assembly must not contribute to the collections it requires.
+    fn requires_collection(&self) -> Vec<TypeKey> {
+        Vec::new()
+    }
+
+    /// Groups the assembly belongs to, e.g. "web" or "background"
+    fn tags(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Names of assemblies this one supersedes, e.g. a mock replacing the real implementation
+    ///
+    /// Overridden assemblies are removed before the dependency graph is built and are never
+    /// initialized. Overrides declared by disabled assemblies have no effect. Names must match
+    /// `name()` exactly, including the module path of default names; `assemble` fails with
+    /// `AssemblyError::UnknownOverride` if no other registered assembly has the name.
+    fn overrides(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Breaks ties between assemblies providing the same service
+    ///
+    /// Only the providers with the highest priority are used; the others are skipped entirely,
+    /// including any other services they provide, and are never initialized.
+    fn priority(&self) -> i32 {
+        0
+    }
 }
 
 /// A subsystem that contributes services to a runtime
+///
+/// Lifecycle hooks run without the assembler's locks held, so they may query the assembler, e.g.
+/// through `initialization_order` or `health_check`. Lifecycle operations called from a hook
+/// while the assembler is assembling or reloading, such as `register`, `assemble` or `reload`,
+/// fail with an error and `shutdown` does nothing.
 pub trait ServiceAssembly: ServiceAssemblyBase {
+    /// Returns whether the assembly participates in a runtime running in the given mode
+    ///
+    /// Disabled assemblies are skipped by `assemble`: they are not initialized and do not
+    /// provide services to other assemblies. Providers of the same service that are enabled for
+    /// different modes therefore never conflict; only providers enabled together are reported
+    /// as duplicates.
+    fn enabled_for(&self, _mode: RuntimeMode) -> bool {
+        true
+    }
+
+    /// Registers configuration and value objects before any assembly is initialized
+    ///
+    /// Every assembly is configured, in initialization order, before the first `init` runs, so
+    /// `init` can resolve configuration registered by its dependencies.
+    fn configure(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        Ok(())
+    }
+
     fn init(&self, context: &MutableAssemblyContext) -> Result<()>;
 
     fn prepare(&self, _context: &MutableAssemblyContext) -> Result<()> {
@@ -141,6 +730,11 @@ pub trait ServiceAssembly: ServiceAssemblyBase {
         Ok(())
     }
 
+    /// Reports whether the assembly is healthy once started, e.g. for readiness probes
+    fn health_check(&self, _context: &AssemblyContext) -> Result<()> {
+        Ok(())
+    }
+
     fn finalize(&self) -> Result<()> {
         Ok(())
     }
@@ -148,187 +742,1561 @@ pub trait ServiceAssembly: ServiceAssemblyBase {
     fn shutdown(&self) -> Result<()> {
         Ok(())
     }
+
+    /// Called when one of the assembly's lifecycle phases returns an error
+    ///
+    /// Runs before the assembler reports the failure, so the assembly can log diagnostics or
+    /// clean up partial state. The error is the one returned by the phase, or a timeout.
+    fn on_error(&self, _phase: Phase, _error: &AssemblyError) {}
 }
 
-pub struct Assembler {
-    assemblies: RwLock<Vec<Arc<dyn ServiceAssembly>>>,
-    registry: Arc<ServiceRegistry>,
-    log_monitor: Arc<dyn LogMonitor>,
-    mode: RuntimeMode,
+/// Time spent in each lifecycle phase of a single assembly
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseDurations {
+    pub init: Duration,
+    pub prepare: Duration,
+    pub start: Duration,
 }
 
-impl Assembler {
-    pub fn new(log_monitor: Arc<dyn LogMonitor>, mode: RuntimeMode) -> Self {
-        Assembler {
-            assemblies: RwLock::new(Vec::new()),
-            registry: Arc::new(ServiceRegistry::new()),
-            log_monitor,
-            mode,
-        }
+impl PhaseDurations {
+    /// Returns the combined duration of all phases
+    pub fn total(&self) -> Duration {
+        self.init + self.prepare + self.start
     }
+}
 
-    /// Registers a service assembly
-    pub fn register(&self, assembly: Arc<dyn ServiceAssembly>) {
-        self.assemblies.write().unwrap().push(assembly);
+/// Timing metrics collected during `Assembler::assemble_with_metrics`
+#[derive(Debug, Clone, Default)]
+pub struct AssemblyMetrics {
+    /// Per-phase durations keyed by assembly name
+    pub assemblies: HashMap<String, PhaseDurations>,
+    /// Wall-clock duration of the whole assembly, including dependency resolution
+    pub total: Duration,
+}
+
+impl AssemblyMetrics {
+    /// Returns the phase durations recorded for the given assembly
+    pub fn get(&self, name: &str) -> Option<&PhaseDurations> {
+        self.assemblies.get(name)
     }
 
-    /// Initializes and prepares registered assemblies in dependency order
-    pub fn assemble(&self) -> Result<()> {
-        // Acquire write lock once at the start
-        let mut assemblies = self.assemblies.write().unwrap();
+    fn phase(&mut self, name: &str) -> &mut PhaseDurations {
+        self.assemblies.entry(name.to_string()).or_default()
+    }
+}
 
-        // Build dependency graph
-        let mut assembly_graph: Graph<String> = Graph::new();
-        let mut mapped_assemblies: HashMap<TypeKey, String> = HashMap::new();
+/// Assemblies in initialization order together with the graph they were sorted from
+struct Resolution {
+    ordered: Vec<Arc<dyn ServiceAssembly>>,
+    graph: Graph<String>,
+    /// The assembly ordering each service's consumers; the last registered provider wins
+    providers: HashMap<TypeKey, String>,
+}
 
-        // Add vertices for each assembly
-        for assembly in assemblies.iter() {
-            let name = assembly.name().to_string();
-            assembly_graph.add_vertex(name.clone(), name.clone());
+impl Resolution {
+    /// Groups the ordered assemblies into levels whose dependencies all live in earlier levels
+    fn levels(&self) -> Vec<Vec<Arc<dyn ServiceAssembly>>> {
+        let mut depth: HashMap<String, usize> = HashMap::new();
+        let mut levels: Vec<Vec<Arc<dyn ServiceAssembly>>> = Vec::new();
 
-            for provided in assembly.provides() {
-                mapped_assemblies.insert(provided, name.clone());
-            }
-        }
+        for assembly in &self.ordered {
+            let level = self
+                .graph
+                .get_vertex(assembly.name())
+                .map(|vertex| {
+                    vertex
+                        .edges
+                        .iter()
+                        .filter_map(|dependency| depth.get(dependency))
+                        .map(|d| d + 1)
+                        .max()
+                        .unwrap_or(0)
+                })
+                .unwrap_or(0);
+            depth.insert(assembly.name().to_string(), level);
 
-        // Add edges for dependencies
-        for assembly in assemblies.iter() {
-            let assembly_name = assembly.name().to_string();
-            for required in assembly.requires() {
-                if let Some(required_assembly) = mapped_assemblies.get(&required) {
-                    assembly_graph.add_edge(&assembly_name, required_assembly);
-                } else {
-                    let error_msg =
-                        format!("Required assembly not found for service: {}", required);
-                    self.log_monitor.error(&format!(
-                        "Failed to resolve dependency in {}: {}",
-                        assembly_name, error_msg
-                    ));
-                    return Err(AssemblyError::MissingDependency {
-                        assembly: assembly_name,
-                        message: error_msg,
-                    });
-                }
+            if levels.len() <= level {
+                levels.resize_with(level + 1, Vec::new);
             }
+            levels[level].push(assembly.clone());
         }
 
-        // Perform topological sort
-        let sort_result = assembly_graph.topological_sort();
-        if sort_result.has_cycle {
-            let cycle_info = if sort_result.cycle_path.is_empty() {
-                "unknown cycle".to_string()
-            } else {
-                format!("Cycle path: {:?}", sort_result.cycle_path)
-            };
-            let error_msg = format!(
-                "Cyclic dependency detected in assembly graph ({})",
-                cycle_info
-            );
-            self.log_monitor.error(&error_msg);
-            return Err(AssemblyError::CyclicDependency(cycle_info));
-        }
+        levels
+    }
+}
 
-        // Reverse the sorted order (dependencies first)
-        let ordered_assemblies = sort_result
-            .sorted_order
-            .iter()
-            .rev()
-            .filter_map(|name| assemblies.iter().find(|a| a.name() == name).cloned())
-            .collect::<Vec<_>>();
+/// Configures an `Assembler` fluently, e.g. `Assembler::builder().mode(mode).build()`
+///
+/// Defaults to Debug mode and a monitor that discards all log messages, like
+/// `Assembler::new_default`.
+pub struct AssemblerBuilder {
+    monitor: Arc<dyn LogMonitor>,
+    mode: RuntimeMode,
+    observer: Option<Arc<dyn LifecycleObserver>>,
+    phase_timeout: Option<Duration>,
+    parallel: bool,
+}
 
-        // Create read-only context for the start phase
-        let context = AssemblyContext {
-            registry: self.registry.clone(),
-            log_monitor: self.log_monitor.clone(),
-            mode: self.mode,
-        };
+impl AssemblerBuilder {
+    pub fn new() -> Self {
+        AssemblerBuilder {
+            monitor: Arc::new(NoopMonitor),
+            mode: RuntimeMode::Debug,
+            observer: None,
+            phase_timeout: None,
+            parallel: false,
+        }
+    }
 
-        // Create mutable context for the init phase
-        let registry_handle = RegistryWriteHandle::new(&self.registry);
-        let init_context = MutableAssemblyContext {
-            registry: registry_handle,
-            log_monitor: self.log_monitor.clone(),
-            mode: self.mode,
-        };
+    /// Sets the monitor receiving the assembler's log messages
+    pub fn monitor(mut self, monitor: Arc<dyn LogMonitor>) -> Self {
+        self.monitor = monitor;
+        self
+    }
 
-        // Initialize assemblies with mutable context
-        for assembly in &ordered_assemblies {
-            assembly.init(&init_context)?;
-            self.log_monitor
-                .debug(&format!("Initialized: {}", assembly.name()));
-        }
+    pub fn mode(mut self, mode: RuntimeMode) -> Self {
+        self.mode = mode;
+        self
+    }
 
-        // Create mutable context for prepare phase
-        let prepare_registry_handle = RegistryWriteHandle::new(&self.registry);
-        let prepare_context = MutableAssemblyContext {
-            registry: prepare_registry_handle,
-            log_monitor: self.log_monitor.clone(),
-            mode: self.mode,
-        };
+    /// See `Assembler::with_observer`
+    pub fn observer(mut self, observer: Arc<dyn LifecycleObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
 
-        // Prepare assemblies with mutable context
-        for assembly in &ordered_assemblies {
-            assembly.prepare(&prepare_context)?;
-            self.log_monitor
-                .debug(&format!("Prepared: {}", assembly.name()));
+    /// See `Assembler::with_phase_timeout`
+    pub fn phase_timeout(mut self, timeout: Duration) -> Self {
+        self.phase_timeout = Some(timeout);
+        self
+    }
+
+    /// Runs independent assemblies in parallel, using up to one thread per available core
+    ///
+    /// See `Assembler::with_max_concurrency`.
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    pub fn build(self) -> Assembler {
+        let mut assembler = Assembler::new(self.monitor, self.mode);
+        if let Some(observer) = self.observer {
+            assembler = assembler.with_observer(observer);
+        }
+        if let Some(timeout) = self.phase_timeout {
+            assembler = assembler.with_phase_timeout(timeout);
         }
+        if self.parallel {
+            let cores = thread::available_parallelism().map_or(1, |n| n.get());
+            assembler = assembler.with_max_concurrency(cores);
+        }
+        assembler
+    }
+}
 
-        // Start assemblies with read-only context
-        for assembly in &ordered_assemblies {
-            assembly.start(&context)?;
-            self.log_monitor
-                .debug(&format!("Started: {}", assembly.name()));
+impl Default for AssemblerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where an assembler is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AssemblerState {
+    /// Assemblies may be registered and deregistered
+    Registering,
+    /// `assemble` is running the lifecycle phases
+    Assembling,
+    /// `assemble` succeeded; the set of assemblies is fixed
+    Assembled,
+    /// `reload` or `reload_assembly` is replacing a running assembly
+    Reloading,
+    /// `shutdown` ran; the assemblies are no longer running
+    ShutDown,
+}
+
+pub struct Assembler {
+    assemblies: RwLock<Vec<Arc<dyn ServiceAssembly>>>,
+    assembled: RwLock<Vec<Arc<dyn ServiceAssembly>>>,
+    /// The dependency graph resolved by the last successful `assemble`
+    assembled_graph: RwLock<Graph<String>>,
+    state: RwLock<AssemblerState>,
+    registry: Arc<ServiceRegistry>,
+    /// Replaceable with `set_monitor`; read at the time each message is logged
+    log_monitor: RwLock<Arc<dyn LogMonitor>>,
+    mode: RuntimeMode,
+    observer: Option<Arc<dyn LifecycleObserver>>,
+    max_concurrency: Option<usize>,
+    monitor_builder: Option<MonitorBuilder>,
+    phase_timeout: Option<Duration>,
+    duplicate_policy: DuplicatePolicy,
+    /// Services each assembly reported through `dynamic_provides` after its `init`
+    dynamic_provisions: RwLock<HashMap<String, Vec<TypeKey>>>,
+    /// The dependency graph most recently built while resolving the initialization order
+    last_graph: RwLock<Option<Graph<String>>>,
+    /// Services resolved through the contexts' `require`, recorded in Debug and Development mode
+    resolution_audit: ResolutionAudit,
+}
+
+impl Assembler {
+    pub fn new(log_monitor: Arc<dyn LogMonitor>, mode: RuntimeMode) -> Self {
+        Self::with_registry(log_monitor, mode, Arc::new(ServiceRegistry::new()))
+    }
+
+    /// Creates an assembler for the given mode that discards all log messages
+    pub fn with_mode(mode: RuntimeMode) -> Self {
+        Self::new(Arc::new(NoopMonitor), mode)
+    }
+
+    /// Creates an assembler in Debug mode that discards all log messages
+    pub fn new_default() -> Self {
+        Self::with_mode(RuntimeMode::Debug)
+    }
+
+    /// Starts configuring an assembler with `AssemblerBuilder`
+    pub fn builder() -> AssemblerBuilder {
+        AssemblerBuilder::new()
+    }
+
+    /// Creates an assembler that registers services into an existing registry
+    ///
+    /// Services already in the registry can be resolved by assemblies during their lifecycle
+    /// phases, e.g. mocks seeded by a test. Note that `restart` clears the registry, including
+    /// services seeded this way.
+    pub fn with_registry(
+        log_monitor: Arc<dyn LogMonitor>,
+        mode: RuntimeMode,
+        registry: Arc<ServiceRegistry>,
+    ) -> Self {
+        Assembler {
+            assemblies: RwLock::new(Vec::new()),
+            assembled: RwLock::new(Vec::new()),
+            assembled_graph: RwLock::new(Graph::new()),
+            state: RwLock::new(AssemblerState::Registering),
+            registry,
+            log_monitor: RwLock::new(log_monitor),
+            mode,
+            observer: None,
+            max_concurrency: None,
+            monitor_builder: None,
+            phase_timeout: None,
+            duplicate_policy: DuplicatePolicy::default(),
+            dynamic_provisions: RwLock::new(HashMap::new()),
+            last_graph: RwLock::new(None),
+            resolution_audit: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Sets an observer notified before and after every lifecycle phase of every assembly
+    pub fn with_observer(mut self, observer: Arc<dyn LifecycleObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Runs the init, prepare and start phases of independent assemblies in parallel
+    ///
+    /// Assemblies are grouped into levels whose dependencies are all satisfied by earlier levels.
+    /// Within a level, at most `n` assemblies run a phase at the same time; a level completes
+    /// before the next one begins. Shutdown remains sequential.
+    pub fn with_max_concurrency(mut self, n: usize) -> Self {
+        self.max_concurrency = Some(n.max(1));
+        self
+    }
+
+    /// Customizes the monitor handed to each assembly's lifecycle contexts
+    ///
+    /// By default every assembly receives a `ScopedMonitor` prefixing messages with its name.
+    pub fn with_monitor_builder(mut self, builder: MonitorBuilder) -> Self {
+        self.monitor_builder = Some(builder);
+        self
+    }
+
+    /// Limits how long a single lifecycle call may run before failing with `PhaseTimeout`
+    ///
+    /// Each call runs on a helper thread that is abandoned when the limit is exceeded. Lifecycle
+    /// methods cannot be interrupted, so a timed-out assembly may still be running in the
+    /// background and can keep mutating the registry until it returns.
+    pub fn with_phase_timeout(mut self, timeout: Duration) -> Self {
+        self.phase_timeout = Some(timeout);
+        self
+    }
+
+    /// Replaces the monitor receiving the assembler's log messages
+    ///
+    /// Lifecycle contexts are created when each phase runs, so the new monitor receives all
+    /// subsequent messages, including those logged by assemblies through their contexts.
+    pub fn set_monitor(&self, monitor: Arc<dyn LogMonitor>) {
+        *self.log_monitor.write().unwrap() = monitor;
+    }
+
+    /// Sets how `register_all` treats assemblies whose name is already registered
+    pub fn with_duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = policy;
+        self
+    }
+
+    /// Registers a service assembly
+    ///
+    /// Fails once the assembler has been assembled, since the assembly would never be
+    /// initialized; use `reload` to replace a running assembly instead.
+    pub fn register(&self, assembly: Arc<dyn ServiceAssembly>) -> Result<()> {
+        // Hold the state lock so registration cannot interleave with the start of an assemble
+        let state = self.state.read().unwrap();
+        Self::check_registering(*state, assembly.name())?;
+        self.assemblies.write().unwrap().push(assembly);
+        Ok(())
+    }
+
+    /// Registers a batch of assemblies, detecting names that are registered more than once
+    ///
+    /// Duplicates are checked against already registered assemblies and within the batch.
+    /// Under `DuplicatePolicy::Reject` the first conflict is returned and nothing is registered.
+    pub fn register_all(&self, batch: Vec<Arc<dyn ServiceAssembly>>) -> Result<()> {
+        let state = self.state.read().unwrap();
+        if let Some(first) = batch.first() {
+            Self::check_registering(*state, first.name())?;
         }
+        let mut assemblies = self.assemblies.write().unwrap();
+
+        if self.duplicate_policy == DuplicatePolicy::Reject {
+            let mut names: HashSet<&str> = assemblies.iter().map(|a| a.name()).collect();
+            for assembly in batch.iter() {
+                if !names.insert(assembly.name()) {
+                    return Err(AssemblyError::DuplicateAssembly(assembly.name().to_string()));
+                }
+            }
+        }
+
+        for assembly in batch {
+            match assemblies.iter().position(|a| a.name() == assembly.name()) {
+                Some(index) => {
+                    self.log_monitor()
+                        .warn(&format!("Replacing registered assembly '{}'", assembly.name()));
+                    assemblies[index] = assembly;
+                }
+                None => assemblies.push(assembly),
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes a registered assembly by name before assembly and returns whether one was removed.
+    ///
+    /// Once assembled, the assembler is left untouched and `false` is returned; use `reload` to
+    /// replace a running assembly instead.
+    pub fn deregister_by_name(&self, name: &str) -> bool {
+        // Hold the state lock so a concurrent assemble cannot observe a partial removal
+        let state = self.state.read().unwrap();
+        if *state != AssemblerState::Registering {
+            return false;
+        }
+        let mut assemblies = self.assemblies.write().unwrap();
+        let before = assemblies.len();
+        assemblies.retain(|a| a.name() != name);
+        assemblies.len() != before
+    }
 
-        // Replace assemblies vec with ordered version
-        *assemblies = ordered_assemblies;
+    /// Initializes and prepares registered assemblies in dependency order
+    ///
+    /// Fails once the assembler has been assembled, or shut down, without running any lifecycle
+    /// phase; use `restart` to assemble again. A failed `assemble` may be retried.
+    pub fn assemble(&self) -> Result<()> {
+        self.assemble_with_metrics().map(|_| ())
+    }
 
+    /// Assembles like `assemble`, then calls `on_ready` once every assembly has started
+    ///
+    /// The callback does not run if any phase fails. It receives a read-only context logging to
+    /// the assembler's monitor and may call back into the assembler.
+    pub fn assemble_then(&self, on_ready: impl FnOnce(&AssemblyContext)) -> Result<()> {
+        self.assemble()?;
+        on_ready(&AssemblyContext {
+            registry: self.registry.clone(),
+            log_monitor: self.log_monitor(),
+            mode: self.mode,
+            current_assembly: String::new(),
+            resolution_audit: None,
+        });
         Ok(())
     }
 
+    /// Assembles like `assemble` and returns how long each assembly spent in each phase
+    pub fn assemble_with_metrics(&self) -> Result<AssemblyMetrics> {
+        let assemble_start = Instant::now();
+
+        // Claim the assembler so the lifecycle hooks can run without holding the assembled lock
+        {
+            let mut state = self.state.write().unwrap();
+            Self::check_can_assemble(*state)?;
+            *state = AssemblerState::Assembling;
+        }
+        let (resolution, mut metrics) = match self.run_lifecycle() {
+            Ok(result) => result,
+            Err(e) => {
+                *self.state.write().unwrap() = AssemblerState::Registering;
+                return Err(e);
+            }
+        };
+
+        // Keep the ordered assemblies for shutdown; the registered list stays untouched
+        let mut assembled = self.assembled.write().unwrap();
+        *assembled = resolution.ordered;
+        *self.assembled_graph.write().unwrap() = resolution.graph;
+        *self.state.write().unwrap() = AssemblerState::Assembled;
+
+        metrics.total = assemble_start.elapsed();
+        Ok(metrics)
+    }
+
+    /// Resolves the registered assemblies and runs their configure, init, prepare and start phases
+    fn run_lifecycle(&self) -> Result<(Resolution, AssemblyMetrics)> {
+        let metrics = Mutex::new(AssemblyMetrics::default());
+        let resolution = self.resolve_order()?;
+
+        // Size the registry for the declared services up front
+        let provided = resolution.ordered.iter().map(|a| a.provides().len()).sum();
+        self.registry.reserve(provided);
+
+        // Configure every assembly before any of them is initialized
+        self.execute(&resolution, Phase::Configure, |assembly| {
+            let configure_context = self.mutable_context(assembly.name(), Phase::Configure);
+            let target = assembly.clone();
+            self.run_assembly_phase(assembly, Phase::Configure, move || {
+                target.configure(&configure_context)
+            })?;
+            self.log_transition("Configured", assembly.name(), Phase::Configure);
+            Ok(())
+        })?;
+
+        // Initialize assemblies with mutable context
+        let init_progress = InitProgress::new(&resolution);
+        self.execute(&resolution, Phase::Init, |assembly| {
+            let mut init_context = self.mutable_context(assembly.name(), Phase::Init);
+            init_context.init_progress = Some(init_progress.clone());
+            let phase_start = Instant::now();
+            let target = assembly.clone();
+            self.run_assembly_phase(assembly, Phase::Init, move || {
+                target.init(&init_context)
+            })?;
+            init_progress.mark_initialized(assembly.name());
+            self.record_provisions(assembly.as_ref())?;
+            metrics.lock().unwrap().phase(assembly.name()).init = phase_start.elapsed();
+            self.log_transition("Initialized", assembly.name(), Phase::Init);
+            Ok(())
+        })?;
+
+        // Prepare assemblies with mutable context
+        self.execute(&resolution, Phase::Prepare, |assembly| {
+            let prepare_context = self.mutable_context(assembly.name(), Phase::Prepare);
+            let phase_start = Instant::now();
+            let target = assembly.clone();
+            self.run_assembly_phase(assembly, Phase::Prepare, move || {
+                target.prepare(&prepare_context)
+            })?;
+            metrics.lock().unwrap().phase(assembly.name()).prepare = phase_start.elapsed();
+            self.log_transition("Prepared", assembly.name(), Phase::Prepare);
+            Ok(())
+        })?;
+
+        // Start assemblies with read-only context
+        self.execute(&resolution, Phase::Start, |assembly| {
+            let context = self.context(assembly.name());
+            let phase_start = Instant::now();
+            let target = assembly.clone();
+            self.run_assembly_phase(assembly, Phase::Start, move || target.start(&context))?;
+            metrics.lock().unwrap().phase(assembly.name()).start = phase_start.elapsed();
+            self.log_transition("Started", assembly.name(), Phase::Start);
+            Ok(())
+        })?;
+
+        Ok((resolution, metrics.into_inner().unwrap()))
+    }
+
+    /// Returns the names of the assembled assemblies in the order they were initialized
+    ///
+    /// The list is empty until `assemble` succeeds.
+    pub fn initialization_order(&self) -> Vec<String> {
+        self.assembled
+            .read()
+            .unwrap()
+            .iter()
+            .map(|a| a.name().to_string())
+            .collect()
+    }
+
+    /// Returns a copy of the dependency graph most recently built by `assemble`, `validate` or
+    /// another call resolving the initialization order
+    ///
+    /// Edges point from an assembly to the assemblies it requires. The graph is kept when sorting
+    /// fails, e.g. on a cycle, but not when dependencies are missing, since edges are only added
+    /// once every requirement is satisfied. Returns `None` until a graph has been built.
+    pub fn last_graph(&self) -> Option<Graph<String>> {
+        self.last_graph.read().unwrap().clone()
+    }
+
+    /// Returns the services assemblies resolved through `require` without declaring them
+    ///
+    /// A service counts as declared if the resolving assembly lists it in `requires`,
+    /// `optional_requires` or `provides`. Entries are in the order they were first resolved.
+    /// Resolutions are only recorded in Debug and Development mode, so this is always empty in
+    /// Production and Test.
+    pub fn undeclared_dependencies(&self) -> Vec<(String, TypeKey)> {
+        let assemblies = self.assemblies.read().unwrap();
+        let resolved = self.resolution_audit.lock().unwrap();
+        resolved
+            .iter()
+            .filter(|(name, key)| {
+                !assemblies.iter().filter(|a| a.name() == name).any(|a| {
+                    a.requires().contains(key)
+                        || a.optional_requires().contains(key)
+                        || a.provides().contains(key)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the services provided by the assembled assembly with the given name
+    ///
+    /// Static `provides` come first, followed by the `dynamic_provides` reported after `init`.
+    /// The list is empty if no assembly with that name has been assembled.
+    pub fn services_provided_by(&self, name: &str) -> Vec<TypeKey> {
+        self.assembled
+            .read()
+            .unwrap()
+            .iter()
+            .find(|a| a.name() == name)
+            .map(|a| self.provisions_of(a.as_ref()))
+            .unwrap_or_default()
+    }
+
+    /// Returns the name of the assembled assembly that provides the given service, if any
+    pub fn provider_of(&self, key: &TypeKey) -> Option<String> {
+        self.assembled
+            .read()
+            .unwrap()
+            .iter()
+            .find(|a| self.provisions_of(a.as_ref()).contains(key))
+            .map(|a| a.name().to_string())
+    }
+
+    /// Returns the names of all registered assemblies in registration order
+    pub fn registered_names(&self) -> Vec<String> {
+        self.assemblies
+            .read()
+            .unwrap()
+            .iter()
+            .map(|a| a.name().to_string())
+            .collect()
+    }
+
+    /// Returns the number of registered assemblies
+    pub fn registered_count(&self) -> usize {
+        self.assemblies.read().unwrap().len()
+    }
+
+    /// Returns the names of the registered assemblies carrying the given tag, in registration order
+    pub fn assemblies_with_tag(&self, tag: &str) -> Vec<String> {
+        self.assemblies
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|a| a.tags().iter().any(|t| t == tag))
+            .map(|a| a.name().to_string())
+            .collect()
+    }
+
+    /// Returns the registry shared by all assemblies so services can be resolved after assembly
+    ///
+    /// Mutating the registry after `assemble`, e.g. through a `RegistryWriteHandle` or `clear`,
+    /// bypasses lifecycle ordering: assemblies that already resolved their dependencies will not
+    /// see the change.
+    pub fn registry(&self) -> Arc<ServiceRegistry> {
+        self.registry.clone()
+    }
+
+    /// Runs the health check of every assembled assembly in initialization order
+    ///
+    /// A failing check does not stop the remaining ones; each assembly's result is reported
+    /// alongside its name. Returns an error if the runtime has not been assembled or has been
+    /// shut down.
+    pub fn health_check(&self) -> Result<Vec<(String, Result<()>)>> {
+        let assemblies = {
+            let assembled = self.assembled.read().unwrap();
+            self.ensure_assembled("run health checks")?;
+            assembled.clone()
+        };
+
+        let results = assemblies
+            .iter()
+            .map(|assembly| {
+                let context = self.context(assembly.name());
+                let target = assembly.clone();
+                let result = self.run_phase(assembly.name(), Phase::HealthCheck, move || {
+                    target.health_check(&context)
+                });
+                if let Err(e) = &result {
+                    self.log_monitor()
+                        .warn(&format!("Health check failed: '{}': {}", assembly.name(), e));
+                }
+                (assembly.name().to_string(), result)
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Checks that all dependencies are satisfiable and acyclic without running any lifecycle phase
+    ///
+    /// Returns the assembly names in the order they would be initialized.
+    pub fn validate(&self) -> Result<Vec<String>> {
+        let resolution = self.resolve_order()?;
+        Ok(resolution
+            .ordered
+            .iter()
+            .map(|a| a.name().to_string())
+            .collect())
+    }
+
+    /// Renders the dependency graph most recently built, see `last_graph`, as an indented tree
+    /// of dependents
+    ///
+    /// Each root, an assembly without requirements, is followed by the assemblies that depend on
+    /// it, each nested one level deeper than the assembly it requires. Assemblies with several
+    /// requirements appear under each of them. The report does not resolve the assemblies itself,
+    /// so it is empty until `assemble` or `validate` has built a graph.
+    pub fn dependency_report(&self) -> String {
+        let graph = self.last_graph.read().unwrap();
+        let Some(graph) = graph.as_ref() else {
+            return String::new();
+        };
+
+        let mut roots: Vec<&String> = graph
+            .vertices
+            .values()
+            .filter(|vertex| vertex.edges.is_empty())
+            .map(|vertex| &vertex.id)
+            .collect();
+        roots.sort();
+
+        let mut report = String::new();
+        for root in roots {
+            Self::write_dependents(graph, root, &mut Vec::new(), &mut report);
+        }
+        report
+    }
+
     /// Finalizes and shuts down assemblies in reverse order
-    /// Attempts to gracefully degrade on errors, collecting all failures
+    /// Attempts to gracefully degrade on errors and panics, collecting all failures
+    ///
+    /// Lifecycle hooks run at most once per `assemble`: calling `shutdown` again, or before
+    /// assembling, is a no-op returning `Ok(())`, even if the first shutdown reported errors.
     pub fn shutdown(&self) -> Result<()> {
-        let mut errors: Vec<String> = Vec::new();
+        // Release the lock before running the hooks so they may query the assembler
+        let reversed: Vec<Arc<dyn ServiceAssembly>> = {
+            let assemblies = self.assembled.read().unwrap();
+            let mut state = self.state.write().unwrap();
+            if *state != AssemblerState::Assembled {
+                return Ok(());
+            }
+            *state = AssemblerState::ShutDown;
+            assemblies.iter().rev().cloned().collect()
+        };
 
-        // Acquire read lock for iteration
-        let assemblies = self.assemblies.read().unwrap();
+        let errors = self.teardown(&reversed);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AssemblyError::ShutdownErrors(errors))
+        }
+    }
+
+    /// Finalizes and shuts down an assembly and every assembly that transitively depends on it
+    ///
+    /// Dependents are taken from the dependency graph resolved by `assemble`, so the registered
+    /// assemblies are not resolved again. They are torn down before the assemblies they require,
+    /// and the services provided by the torn down assemblies are removed from the registry. All
+    /// other assemblies keep running.
+    /// Failures are collected as in `shutdown`. Fails unless the assembler is assembled and has
+    /// not been shut down.
+    pub fn shutdown_subtree(&self, name: &str) -> Result<()> {
+        let mut assembled = self.assembled.write().unwrap();
+        self.ensure_assembled(&format!("shut down '{}'", name))?;
+        if !assembled.iter().any(|a| a.name() == name) {
+            return Err(AssemblyError::GeneralError(format!("Assembly not found: {}", name)));
+        }
+
+        // Assembler edges point from a consumer to the assembly it requires
+        let graph = self.assembled_graph.read().unwrap();
+        let mut subtree: HashSet<String> = HashSet::new();
+        let mut pending = vec![name.to_string()];
+        while let Some(current) = pending.pop() {
+            if subtree.insert(current.clone()) {
+                pending.extend(graph.get_dependencies(&current));
+            }
+        }
+        drop(graph);
+
+        // Reverse initialization order tears down dependents first; the targets stop being
+        // tracked before their hooks run without the lock held
+        let targets: Vec<Arc<dyn ServiceAssembly>> = assembled
+            .iter()
+            .rev()
+            .filter(|a| subtree.contains(a.name()))
+            .cloned()
+            .collect();
+        assembled.retain(|a| !subtree.contains(a.name()));
+        drop(assembled);
+        let errors = self.teardown(&targets);
+
+        for target in &targets {
+            for provided in self.provisions_of(target.as_ref()) {
+                self.registry.remove(&provided);
+            }
+            self.dynamic_provisions.write().unwrap().remove(target.name());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AssemblyError::ShutdownErrors(errors))
+        }
+    }
+
+    /// Shuts down all assemblies, clears the registry and assembles the registered assemblies again
+    ///
+    /// Assemblies are re-resolved from the list passed to `register`, so a restart picks up the
+    /// same assemblies as the original `assemble`. If shutdown fails, the runtime is not
+    /// re-assembled and the shutdown error is returned.
+    pub fn restart(&self) -> Result<()> {
+        if matches!(
+            *self.state.read().unwrap(),
+            AssemblerState::Assembling | AssemblerState::Reloading
+        ) {
+            return Err(AssemblyError::GeneralError(
+                "Cannot restart while the assembler is assembling or reloading".to_string(),
+            ));
+        }
+        self.shutdown()?;
+        self.assembled.write().unwrap().clear();
+        *self.assembled_graph.write().unwrap() = Graph::new();
+        *self.state.write().unwrap() = AssemblerState::Registering;
+        self.dynamic_provisions.write().unwrap().clear();
+        self.resolution_audit.lock().unwrap().clear();
+        self.registry.clear();
+        self.log_monitor().debug("Restarting");
+        self.assemble()
+    }
+
+    /// Replaces a single assembly and re-runs its lifecycle without restarting the runtime
+    ///
+    /// The new instance is configured, initialized, prepared and started, re-registering its
+    /// services, and then takes the place of the old instance, which is finalized and shut down.
+    /// The replacement must have the same name and provide the same services as the assembly it
+    /// replaces.
+    ///
+    /// Dependents are not re-initialized: any that resolved and kept an `Arc` to a service of
+    /// the old instance continue to use it. If a phase of the replacement fails, the registry is
+    /// restored to its state before the reload, dropping anything registered in the meantime,
+    /// the replacement is discarded without being finalized, and the old instance keeps running. If
+    /// the old instance fails to finalize or shut down, the replacement stays in its place and
+    /// the failures are returned as `AssemblyError::ShutdownErrors`. Fails unless the assembler
+    /// is assembled and has not been shut down.
+    pub fn reload(&self, name: &str, new_assembly: Arc<dyn ServiceAssembly>) -> Result<()> {
+        self.swap_assembly(name, new_assembly, false)
+    }
+
+    /// Hot-swaps a single assembly like `reload`, starting the replacement from a clean registry
+    ///
+    /// The replacement must provide and require the same services as the original, so the
+    /// dependency graph stays valid. The services provided by the old instance, including those
+    /// reported through `dynamic_provides`, are removed from the registry before the replacement
+    /// is configured, so dependents resolving after the swap see only what the new instance
+    /// registers. The swap is all-or-nothing: if the replacement fails, the old instance keeps
+    /// running and its services, static and dynamic, are registered and reported again.
+    pub fn reload_assembly(&self, name: &str, new: Arc<dyn ServiceAssembly>) -> Result<()> {
+        self.swap_assembly(name, new, true)
+    }
+
+    /// Replaces a running assembly; `strict` also compares `requires` and clears old provisions
+    fn swap_assembly(
+        &self,
+        name: &str,
+        new_assembly: Arc<dyn ServiceAssembly>,
+        strict: bool,
+    ) -> Result<()> {
+        // Claim the assembler so the replacement's hooks run without holding the assembled lock
+        {
+            let mut state = self.state.write().unwrap();
+            Self::check_assembled(*state, &format!("reload '{}'", name))?;
+            *state = AssemblerState::Reloading;
+        }
+        let result = self.replace_running(name, new_assembly, strict);
+        *self.state.write().unwrap() = AssemblerState::Assembled;
+        result
+    }
+
+    fn replace_running(
+        &self,
+        name: &str,
+        new_assembly: Arc<dyn ServiceAssembly>,
+        strict: bool,
+    ) -> Result<()> {
+        let old_assembly = self
+            .assembled
+            .read()
+            .unwrap()
+            .iter()
+            .find(|a| a.name() == name)
+            .cloned()
+            .ok_or_else(|| AssemblyError::GeneralError(format!("Assembly not found: {}", name)))?;
+        if new_assembly.name() != name {
+            return Err(AssemblyError::GeneralError(format!(
+                "Cannot reload '{}': the replacement is named '{}'",
+                name,
+                new_assembly.name()
+            )));
+        }
+
+        let old_provides: HashSet<TypeKey> = old_assembly.provides().into_iter().collect();
+        let new_provides: HashSet<TypeKey> = new_assembly.provides().into_iter().collect();
+        if old_provides != new_provides {
+            return Err(AssemblyError::GeneralError(format!(
+                "Cannot reload '{}': provided services differ from the original assembly",
+                name
+            )));
+        }
+        if strict {
+            let old_requires: HashSet<TypeKey> = old_assembly.requires().into_iter().collect();
+            let new_requires: HashSet<TypeKey> = new_assembly.requires().into_iter().collect();
+            if old_requires != new_requires {
+                return Err(AssemblyError::GeneralError(format!(
+                    "Cannot reload '{}': required services differ from the original assembly",
+                    name
+                )));
+            }
+        }
+
+        // Bring up the replacement while the old instance keeps running, so a failure can be
+        // rolled back without restarting it
+        let snapshot = self.registry.snapshot();
+        let old_dynamic = self.dynamic_provisions.read().unwrap().get(name).cloned();
+        if strict {
+            for key in self.provisions_of(old_assembly.as_ref()) {
+                self.registry.remove(&key);
+            }
+            self.dynamic_provisions.write().unwrap().remove(name);
+        }
+        if let Err(e) = self.bring_up(&new_assembly) {
+            self.registry.restore(snapshot);
+            let mut dynamic_provisions = self.dynamic_provisions.write().unwrap();
+            match old_dynamic {
+                Some(dynamic) => dynamic_provisions.insert(name.to_string(), dynamic),
+                None => dynamic_provisions.remove(name),
+            };
+            return Err(e);
+        }
+
+        let mut assembled = self.assembled.write().unwrap();
+        if let Some(running) = assembled.iter_mut().find(|a| a.name() == name) {
+            *running = new_assembly.clone();
+        }
+        for registered in self.assemblies.write().unwrap().iter_mut() {
+            if registered.name() == name {
+                *registered = new_assembly.clone();
+            }
+        }
+
+        drop(assembled);
+
+        // The replacement is running, so failures of the old instance are only reported
+        let errors = self.teardown(std::slice::from_ref(&old_assembly));
+        if !errors.is_empty() {
+            return Err(AssemblyError::ShutdownErrors(errors));
+        }
+
+        self.log_monitor()
+            .debug(&format!("Reloaded: {}", new_assembly.name()));
+
+        Ok(())
+    }
+
+    /// Configures, initializes, prepares and starts a single assembly, recording its provisions
+    fn bring_up(&self, assembly: &Arc<dyn ServiceAssembly>) -> Result<()> {
+        let name = assembly.name();
+        let configure_context = self.mutable_context(name, Phase::Configure);
+        let target = assembly.clone();
+        self.run_assembly_phase(assembly, Phase::Configure, move || {
+            target.configure(&configure_context)
+        })?;
+        let init_context = self.mutable_context(name, Phase::Init);
+        let target = assembly.clone();
+        self.run_assembly_phase(assembly, Phase::Init, move || target.init(&init_context))?;
+        self.record_provisions(assembly.as_ref())?;
+        let prepare_context = self.mutable_context(name, Phase::Prepare);
+        let target = assembly.clone();
+        self.run_assembly_phase(assembly, Phase::Prepare, move || target.prepare(&prepare_context))?;
+        let context = self.context(name);
+        let target = assembly.clone();
+        self.run_assembly_phase(assembly, Phase::Start, move || target.start(&context))
+    }
+
+    /// Builds the dependency graph of the enabled registered assemblies and returns them in
+    /// initialization order
+    fn resolve_order(&self) -> Result<Resolution> {
+        let assemblies = self.assemblies.read().unwrap().clone();
+
+        // Skip assemblies that are disabled for the current mode
+        let enabled_assemblies = assemblies
+            .iter()
+            .filter(|a| a.enabled_for(self.mode))
+            .cloned()
+            .collect::<Vec<_>>();
+        let enabled_assemblies = self.skip_overridden(&assemblies, enabled_assemblies)?;
+        let enabled_assemblies = self.skip_lower_priority_providers(enabled_assemblies);
+
+        // An assembly requiring its own service or collection, even optionally, is a declaration
+        // mistake, not a real cycle
+        for assembly in enabled_assemblies.iter() {
+            let provided = assembly.provides();
+            let contributed = assembly.provides_collection();
+            let own_service = assembly
+                .requires()
+                .into_iter()
+                .chain(assembly.optional_requires())
+                .find(|r| provided.contains(r));
+            let own_collection = || {
+                assembly
+                    .requires_collection()
+                    .into_iter()
+                    .find(|r| contributed.contains(r))
+            };
+            if let Some(type_key) = own_service.or_else(own_collection) {
+                let error = AssemblyError::SelfDependency {
+                    assembly: assembly.name().to_string(),
+                    type_key,
+                };
+                self.log_monitor().error(&error.to_string());
+                return Err(error);
+            }
+        }
+
+        // Build dependency graph
+        let mut assembly_graph: Graph<String> = Graph::new();
+        let mut mapped_assemblies: HashMap<TypeKey, String> = HashMap::new();
+        let mut providers: HashMap<TypeKey, Vec<String>> = HashMap::new();
+        let mut contributors: HashMap<TypeKey, Vec<String>> = HashMap::new();
+
+        // Add vertices for each assembly
+        for assembly in enabled_assemblies.iter() {
+            let name = assembly.name().to_string();
+            if !assembly_graph.add_vertex(name.clone(), name.clone()) {
+                self.log_monitor().warn(&format!(
+                    "Assembly name '{}' is shared by several assemblies; they are treated as one",
+                    name
+                ));
+            }
+
+            for provided in assembly.provides() {
+                let names = providers.entry(provided.clone()).or_default();
+                if names.last() != Some(&name) {
+                    names.push(name.clone());
+                }
+                mapped_assemblies.insert(provided, name.clone());
+            }
+            for collection in assembly.provides_collection() {
+                contributors.entry(collection).or_default().push(name.clone());
+            }
+        }
+
+        // Detect services provided by more than one assembly
+        let mut duplicates = providers
+            .into_iter()
+            .filter(|(_, names)| names.len() > 1)
+            .collect::<Vec<_>>();
+        duplicates.sort_by_key(|(type_key, _)| type_key.to_string());
+        for (type_key, names) in duplicates {
+            if self.mode.is_production() {
+                let error = AssemblyError::DuplicateProvider {
+                    type_key,
+                    assemblies: names,
+                };
+                self.log_monitor().error(&error.to_string());
+                return Err(error);
+            }
+            self.log_monitor().warn(&format!(
+                "Service {} is provided by multiple assemblies: {}; using '{}'",
+                type_key,
+                names.join(", "),
+                mapped_assemblies[&type_key]
+            ));
+        }
+
+        // Report every unsatisfied requirement at once rather than stopping at the first,
+        // including collections without a contributor
+        let missing: Vec<(String, TypeKey)> = enabled_assemblies
+            .iter()
+            .flat_map(|assembly| {
+                let services = assembly
+                    .requires()
+                    .into_iter()
+                    .filter(|required| !mapped_assemblies.contains_key(required));
+                let collections = assembly
+                    .requires_collection()
+                    .into_iter()
+                    .filter(|collection| !contributors.contains_key(collection));
+                services
+                    .chain(collections)
+                    .map(|required| (assembly.name().to_string(), required))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        if !missing.is_empty() {
+            let error = AssemblyError::MissingDependencies(missing);
+            self.log_monitor().error(&error.to_string());
+            return Err(error);
+        }
+
+        // Add edges for dependencies
+        for assembly in enabled_assemblies.iter() {
+            let assembly_name = assembly.name().to_string();
+            for required in assembly.requires() {
+                if let Some(required_assembly) = mapped_assemblies.get(&required) {
+                    assembly_graph.add_edge(&assembly_name, required_assembly);
+                }
+            }
+            for optional in assembly.optional_requires() {
+                if let Some(optional_assembly) = mapped_assemblies.get(&optional) {
+                    assembly_graph.add_edge(&assembly_name, optional_assembly);
+                }
+            }
+            for collection in assembly.requires_collection() {
+                if let Some(names) = contributors.get(&collection) {
+                    for contributor in names {
+                        assembly_graph.add_edge(&assembly_name, contributor);
+                    }
+                }
+            }
+        }
+
+        // Keep the graph for inspection even if sorting fails
+        *self.last_graph.write().unwrap() = Some(assembly_graph.clone());
+
+        // Perform topological sort
+        let sort_result = assembly_graph.topological_sort();
+        if sort_result.has_cycle {
+            let cycle_info = if sort_result.cycle_path.is_empty() {
+                "unknown cycle".to_string()
+            } else {
+                sort_result.cycle_path.join(" -> ")
+            };
+            let services = Self::cycle_services(
+                &enabled_assemblies,
+                &sort_result.cycle_path,
+                &mapped_assemblies,
+                &contributors,
+            );
+            let error = AssemblyError::CyclicDependency {
+                cycle: cycle_info,
+                services,
+            };
+            self.log_monitor().error(&error.to_string());
+            return Err(error);
+        }
+
+        // Reverse the sorted order (dependencies first)
+        let ordered_assemblies = sort_result
+            .sorted_order
+            .iter()
+            .rev()
+            .filter_map(|name| enabled_assemblies.iter().find(|a| a.name() == name).cloned())
+            .collect::<Vec<_>>();
+
+        Ok(Resolution {
+            ordered: ordered_assemblies,
+            graph: assembly_graph,
+            providers: mapped_assemblies,
+        })
+    }
+
+    /// Drops assemblies named in the overrides of another assembly
+    ///
+    /// An override must name another registered assembly, usually by its full default name such
+    /// as "my_app::db::DatabaseAssembly". Naming a registered assembly that is disabled for the
+    /// current mode is not an error.
+    fn skip_overridden(
+        &self,
+        registered: &[Arc<dyn ServiceAssembly>],
+        assemblies: Vec<Arc<dyn ServiceAssembly>>,
+    ) -> Result<Vec<Arc<dyn ServiceAssembly>>> {
+        let mut overridden: HashSet<String> = HashSet::new();
+        for assembly in &assemblies {
+            for name in assembly.overrides() {
+                if name == assembly.name() || !registered.iter().any(|a| a.name() == name) {
+                    let error = AssemblyError::UnknownOverride {
+                        assembly: assembly.name().to_string(),
+                        overridden: name,
+                    };
+                    self.log_monitor().error(&error.to_string());
+                    return Err(error);
+                }
+                if assemblies.iter().any(|a| a.name() == name) {
+                    self.log_monitor()
+                        .info(&format!("Assembly '{}' is overridden by '{}'", name, assembly.name()));
+                    overridden.insert(name);
+                }
+            }
+        }
+
+        Ok(assemblies
+            .into_iter()
+            .filter(|a| !overridden.contains(a.name()))
+            .collect())
+    }
+
+    /// Drops providers that share a service with a higher-priority provider
+    ///
+    /// Providers tied at the highest priority are all kept and handled as duplicates.
+    fn skip_lower_priority_providers(
+        &self,
+        assemblies: Vec<Arc<dyn ServiceAssembly>>,
+    ) -> Vec<Arc<dyn ServiceAssembly>> {
+        let mut providers: HashMap<TypeKey, Vec<&Arc<dyn ServiceAssembly>>> = HashMap::new();
+        for assembly in &assemblies {
+            for provided in assembly.provides() {
+                let entries = providers.entry(provided).or_default();
+                if !entries.iter().any(|a| a.name() == assembly.name()) {
+                    entries.push(assembly);
+                }
+            }
+        }
+
+        let mut contested = providers
+            .into_iter()
+            .filter(|(_, entries)| entries.len() > 1)
+            .collect::<Vec<_>>();
+        contested.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut skipped: HashSet<String> = HashSet::new();
+        for (type_key, entries) in contested {
+            let top = entries.iter().map(|a| a.priority()).max().unwrap_or_default();
+            let (winners, losers): (Vec<_>, Vec<_>) =
+                entries.into_iter().partition(|a| a.priority() == top);
+            if losers.is_empty() {
+                continue;
+            }
+            let winners = winners.iter().map(|a| a.name()).collect::<Vec<_>>();
+            let losers = losers.iter().map(|a| a.name()).collect::<Vec<_>>();
+            self.log_monitor().info(&format!(
+                "Service {} is provided by multiple assemblies; using '{}' with priority {} and skipping {}",
+                type_key,
+                winners.join("', '"),
+                top,
+                losers.join(", ")
+            ));
+            skipped.extend(losers.into_iter().map(String::from));
+        }
+
+        assemblies
+            .into_iter()
+            .filter(|a| !skipped.contains(a.name()))
+            .collect()
+    }
+
+    /// Runs a task for every resolved assembly, level by level when concurrency is enabled
+    ///
+    /// Sequential execution stops at the first failure. Parallel execution finishes the current
+    /// level and then reports the failure of the earliest assembly in that level.
+    fn execute<F>(&self, resolution: &Resolution, phase: Phase, task: F) -> Result<()>
+    where
+        F: Fn(&Arc<dyn ServiceAssembly>) -> Result<()> + Sync,
+    {
+        let total = resolution.ordered.len();
+        let begun = AtomicUsize::new(0);
+        let task = |assembly: &Arc<dyn ServiceAssembly>| {
+            if let Some(observer) = &self.observer {
+                let index = begun.fetch_add(1, Ordering::SeqCst);
+                observer.on_progress(assembly.name(), phase, index, total);
+            }
+            task(assembly)
+        };
+
+        let Some(max_concurrency) = self.max_concurrency else {
+            for assembly in &resolution.ordered {
+                task(assembly)?;
+            }
+            return Ok(());
+        };
+
+        for level in resolution.levels() {
+            let next = AtomicUsize::new(0);
+            let failures: Mutex<Vec<(usize, AssemblyError)>> = Mutex::new(Vec::new());
+
+            thread::scope(|scope| {
+                for _ in 0..max_concurrency.min(level.len()) {
+                    scope.spawn(|| {
+                        loop {
+                            let index = next.fetch_add(1, Ordering::SeqCst);
+                            let Some(assembly) = level.get(index) else {
+                                break;
+                            };
+                            if let Err(e) = task(assembly) {
+                                failures.lock().unwrap().push((index, e));
+                            }
+                        }
+                    });
+                }
+            });
+
+            let mut failures = failures.into_inner().unwrap();
+            failures.sort_by_key(|(index, _)| *index);
+            if let Some((_, e)) = failures.into_iter().next() {
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs a single lifecycle phase, notifying the observer before and after
+    fn run_phase(
+        &self,
+        assembly: &str,
+        phase: Phase,
+        f: impl FnOnce() -> Result<()> + Send + 'static,
+    ) -> Result<()> {
+        if let Some(observer) = &self.observer {
+            observer.on_phase_start(assembly, phase);
+        }
+        let result = self.call_with_timeout(assembly, phase, f);
+        if let Some(observer) = &self.observer {
+            observer.on_phase_end(assembly, phase, &result);
+        }
+        result
+    }
+
+    /// Finalizes, then shuts down, the assemblies in the given order and collects every failure
+    fn teardown(&self, assemblies: &[Arc<dyn ServiceAssembly>]) -> Vec<(String, AssemblyError)> {
+        let mut errors: Vec<(String, AssemblyError)> = Vec::new();
 
         // Finalize assemblies
-        for assembly in assemblies.iter().rev() {
-            match assembly.finalize() {
+        for assembly in assemblies {
+            let target = assembly.clone();
+            let finalize = move || {
+                catch_teardown_panic(target.name(), Phase::Finalize, || target.finalize())
+            };
+            match self.run_phase(assembly.name(), Phase::Finalize, finalize) {
                 Ok(_) => {
-                    self.log_monitor
-                        .debug(&format!("Finalized: {}", assembly.name()));
+                    self.log_transition("Finalized", assembly.name(), Phase::Finalize);
                 }
                 Err(e) => {
-                    let error_msg = format!("Finalize: '{}': {}", assembly.name(), e);
-                    errors.push(error_msg);
+                    self.log_monitor()
+                        .error(&format!("Finalize: '{}': {}", assembly.name(), e));
+                    assembly.on_error(Phase::Finalize, &e);
+                    errors.push((assembly.name().to_string(), e));
                 }
             }
         }
 
         // Shutdown assemblies
-        for assembly in assemblies.iter().rev() {
-            match assembly.shutdown() {
+        for assembly in assemblies {
+            let target = assembly.clone();
+            let shutdown = move || {
+                catch_teardown_panic(target.name(), Phase::Shutdown, || target.shutdown())
+            };
+            match self.run_phase(assembly.name(), Phase::Shutdown, shutdown) {
                 Ok(_) => {
-                    self.log_monitor
-                        .debug(&format!("Shutdown: {}", assembly.name()));
+                    self.log_transition("Shutdown", assembly.name(), Phase::Shutdown);
                 }
                 Err(e) => {
-                    let error_msg = format!("Shutdown: {}: {}", assembly.name(), e);
-                    errors.push(error_msg);
+                    self.log_monitor()
+                        .error(&format!("Shutdown: '{}': {}", assembly.name(), e));
+                    assembly.on_error(Phase::Shutdown, &e);
+                    errors.push((assembly.name().to_string(), e));
                 }
             }
         }
 
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(AssemblyError::GeneralError(format!(
-                "Errors shutting down:\n {}",
-                errors.join("\n")
-            )))
+        errors
+    }
+
+    /// Merges an assembly's static provides with the dynamic provides recorded after its init
+    fn provisions_of(&self, assembly: &dyn ServiceAssembly) -> Vec<TypeKey> {
+        let mut services = assembly.provides();
+        if let Some(dynamic) = self.dynamic_provisions.read().unwrap().get(assembly.name()) {
+            for key in dynamic {
+                if !services.contains(key) {
+                    services.push(key.clone());
+                }
+            }
+        }
+        services
+    }
+
+    /// Verifies and records the static and dynamic provisions of an initialized assembly
+    fn record_provisions(&self, assembly: &dyn ServiceAssembly) -> Result<()> {
+        let dynamic = assembly.dynamic_provides();
+        self.verify_provisions(assembly, &dynamic)?;
+        self.dynamic_provisions
+            .write()
+            .unwrap()
+            .insert(assembly.name().to_string(), dynamic);
+        Ok(())
+    }
+
+    /// Checks that an initialized assembly registered every service it declares to provide
+    ///
+    /// Only runs in Debug and Development modes to keep production start-up lean.
+    fn verify_provisions(&self, assembly: &dyn ServiceAssembly, dynamic: &[TypeKey]) -> Result<()> {
+        if !matches!(self.mode, RuntimeMode::Debug | RuntimeMode::Development) {
+            return Ok(());
+        }
+        match assembly
+            .provides()
+            .into_iter()
+            .chain(dynamic.iter().cloned())
+            .find(|key| !key.is_dynamic() && !self.registry.contains_key(key))
+        {
+            Some(type_key) => Err(AssemblyError::UnfulfilledProvision {
+                assembly: assembly.name().to_string(),
+                type_key,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Maps each step of a cycle back to the service that makes one assembly depend on the next
+    fn cycle_services(
+        assemblies: &[Arc<dyn ServiceAssembly>],
+        cycle_path: &[String],
+        mapped_assemblies: &HashMap<TypeKey, String>,
+        contributors: &HashMap<TypeKey, Vec<String>>,
+    ) -> Vec<TypeKey> {
+        cycle_path
+            .windows(2)
+            .filter_map(|step| {
+                let assembly = assemblies.iter().find(|a| a.name() == step[0])?;
+                let provided_by_next =
+                    |key: &TypeKey| mapped_assemblies.get(key) == Some(&step[1]);
+                assembly
+                    .requires()
+                    .into_iter()
+                    .chain(assembly.optional_requires())
+                    .find(provided_by_next)
+                    .or_else(|| {
+                        assembly.requires_collection().into_iter().find(|key| {
+                            contributors.get(key).is_some_and(|names| names.contains(&step[1]))
+                        })
+                    })
+            })
+            .collect()
+    }
+
+    /// Appends an assembly and, recursively, the assemblies that require it
+    fn write_dependents(
+        graph: &Graph<String>,
+        name: &str,
+        ancestors: &mut Vec<String>,
+        report: &mut String,
+    ) {
+        report.push_str(&format!("{}{}\n", "  ".repeat(ancestors.len()), name));
+        ancestors.push(name.to_string());
+        // Assembler edges point from a consumer to the assembly it requires; a graph kept from a
+        // failed resolution may contain cycles
+        for dependent in graph.get_dependencies(name) {
+            if !ancestors.contains(&dependent) {
+                Self::write_dependents(graph, &dependent, ancestors, report);
+            }
+        }
+        ancestors.pop();
+    }
+
+    /// Returns an error naming the assembly if registration is no longer possible
+    fn check_registering(state: AssemblerState, assembly: &str) -> Result<()> {
+        match state {
+            AssemblerState::Registering => Ok(()),
+            AssemblerState::Assembling => Err(AssemblyError::GeneralError(format!(
+                "Cannot register '{}' while assembling",
+                assembly
+            ))),
+            AssemblerState::Assembled | AssemblerState::Reloading => {
+                Err(AssemblyError::GeneralError(format!(
+                    "Cannot register '{}' after assemble; use reload to replace a running assembly",
+                    assembly
+                )))
+            }
+            AssemblerState::ShutDown => Err(AssemblyError::GeneralError(format!(
+                "Cannot register '{}' after shutdown; use restart to assemble again",
+                assembly
+            ))),
+        }
+    }
+
+    /// Fails unless the assemblies are still being registered, so each lifecycle hook runs at
+    /// most once per `assemble`
+    fn check_can_assemble(state: AssemblerState) -> Result<()> {
+        let reason = match state {
+            AssemblerState::Registering => return Ok(()),
+            AssemblerState::Assembling => "while assembling",
+            AssemblerState::Assembled => "again; use restart to re-run the lifecycle",
+            AssemblerState::Reloading => "while an assembly is being reloaded",
+            AssemblerState::ShutDown => "after shutdown; use restart to assemble again",
+        };
+        Err(AssemblyError::GeneralError(format!("Cannot assemble {}", reason)))
+    }
+
+    /// Fails unless `assemble` succeeded and the assemblies have not been shut down since
+    fn ensure_assembled(&self, operation: &str) -> Result<()> {
+        Self::check_assembled(*self.state.read().unwrap(), operation)
+    }
+
+    fn check_assembled(state: AssemblerState, operation: &str) -> Result<()> {
+        match state {
+            AssemblerState::Assembled => Ok(()),
+            AssemblerState::Registering => Err(AssemblyError::GeneralError(format!(
+                "Cannot {} before assemble",
+                operation
+            ))),
+            AssemblerState::Assembling => Err(AssemblyError::GeneralError(format!(
+                "Cannot {} while assembling",
+                operation
+            ))),
+            AssemblerState::Reloading => Err(AssemblyError::GeneralError(format!(
+                "Cannot {} while an assembly is being reloaded",
+                operation
+            ))),
+            AssemblerState::ShutDown => Err(AssemblyError::GeneralError(format!(
+                "Cannot {} after shutdown; use restart to assemble again",
+                operation
+            ))),
+        }
+    }
+
+    /// Logs the completion of a lifecycle phase with the assembly and phase as fields
+    fn log_transition(&self, message: &str, assembly: &str, phase: Phase) {
+        let phase = phase.to_string();
+        self.log_monitor().event(
+            Level::Debug,
+            &format!("{}: {}", message, assembly),
+            &[("assembly", assembly), ("phase", &phase)],
+        );
+    }
+
+    /// Runs a single lifecycle phase and attributes any failure to the assembly and phase
+    fn run_assembly_phase(
+        &self,
+        assembly: &Arc<dyn ServiceAssembly>,
+        phase: Phase,
+        f: impl FnOnce() -> Result<()> + Send + 'static,
+    ) -> Result<()> {
+        self.run_phase(assembly.name(), phase, f).map_err(|e| {
+            assembly.on_error(phase, &e);
+            match e {
+                // A timeout already names the assembly and phase
+                AssemblyError::PhaseTimeout { .. } => e,
+                e => AssemblyError::PhaseFailure {
+                    assembly: assembly.name().to_string(),
+                    phase,
+                    source: Box::new(e),
+                },
+            }
+        })
+    }
+
+    /// Invokes a lifecycle call, on a watchdog thread when a phase timeout is configured
+    fn call_with_timeout(
+        &self,
+        assembly: &str,
+        phase: Phase,
+        f: impl FnOnce() -> Result<()> + Send + 'static,
+    ) -> Result<()> {
+        let Some(timeout) = self.phase_timeout else {
+            return f();
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name(format!("{}-{}", assembly, phase))
+            .spawn(move || {
+                // The receiver is gone if the call timed out; the result is discarded
+                let _ = sender.send(f());
+            })
+            .map_err(|e| {
+                AssemblyError::GeneralError(format!("Failed to spawn {} thread: {}", phase, e))
+            })?;
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                self.log_monitor().warn(&format!(
+                    "'{}' exceeded the phase timeout of {:?} during {}",
+                    assembly, timeout, phase
+                ));
+                Err(AssemblyError::PhaseTimeout {
+                    assembly: assembly.to_string(),
+                    phase,
+                })
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(AssemblyError::GeneralError(format!(
+                "'{}' panicked during {}",
+                assembly, phase
+            ))),
+        }
+    }
+
+    /// Creates a read-only context for the start phase
+    fn context(&self, assembly: &str) -> AssemblyContext {
+        AssemblyContext {
+            registry: self.registry.clone(),
+            log_monitor: self.scoped_monitor(assembly),
+            mode: self.mode,
+            current_assembly: assembly.to_string(),
+            resolution_audit: self.resolution_audit(),
+        }
+    }
+
+    /// Returns the audit handed to contexts, which is only kept in Debug and Development mode
+    fn resolution_audit(&self) -> Option<ResolutionAudit> {
+        matches!(self.mode, RuntimeMode::Debug | RuntimeMode::Development)
+            .then(|| self.resolution_audit.clone())
+    }
+
+    /// Returns the monitor currently receiving the assembler's log messages
+    fn log_monitor(&self) -> Arc<dyn LogMonitor> {
+        self.log_monitor.read().unwrap().clone()
+    }
+
+    /// Creates the monitor handed to the given assembly
+    fn scoped_monitor(&self, assembly: &str) -> Arc<dyn LogMonitor> {
+        match &self.monitor_builder {
+            Some(builder) => builder(assembly, self.log_monitor()),
+            None => Arc::new(ScopedMonitor::new(assembly, self.log_monitor())),
+        }
+    }
+
+    /// Creates a context with write access to the registry for the init and prepare phases
+    ///
+    /// Every registration made through the context is logged with the assembly and phase.
+    fn mutable_context(&self, assembly: &str, phase: Phase) -> MutableAssemblyContext {
+        let scoped_monitor = self.scoped_monitor(assembly);
+        let log_monitor = self.log_monitor();
+        let current_assembly = assembly.to_string();
+        let assembly = assembly.to_string();
+        let observer: RegisterObserver = Arc::new(move |type_name| {
+            log_monitor.debug(&format!(
+                "Registered: {} by '{}' during {}",
+                type_name, assembly, phase
+            ));
+        });
+
+        MutableAssemblyContext {
+            registry: RegistryWriteHandle::with_observer(&self.registry, observer),
+            log_monitor: scoped_monitor,
+            mode: self.mode,
+            current_assembly,
+            resolution_audit: self.resolution_audit(),
+            init_progress: None,
         }
     }
 }
@@ -11,8 +11,8 @@
 
 #![allow(dead_code)]
 
-use std::collections::HashMap;
-use std::fmt;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write};
 
 const UNVISITED: u8 = 0;
 const VISITING: u8 = 1;
@@ -20,6 +20,7 @@ const VISITED: u8 = 2;
 
 /// A node in the graph
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vertex<T: Clone> {
     pub id: String,
     pub value: T,
@@ -32,20 +33,44 @@ impl<T: Clone + fmt::Debug> fmt::Display for Vertex<T> {
     }
 }
 
+/// Vertices are equal when their IDs, values and sets of edges match; edge order is ignored
+impl<T: Clone + PartialEq> PartialEq for Vertex<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.value == other.value
+            && self.edges.iter().collect::<HashSet<_>>() == other.edges.iter().collect::<HashSet<_>>()
+    }
+}
+
 /// A directed acyclic graph
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Graph<T: Clone> {
     pub vertices: HashMap<String, Vertex<T>>,
 }
 
 /// Contains both the sorted order and any detected cycles
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SortResult {
     pub sorted_order: Vec<String>,
     pub has_cycle: bool,
     pub cycle_path: Vec<String>,
 }
 
+impl SortResult {
+    /// Maps each vertex ID to its index in `sorted_order`
+    ///
+    /// The map is empty when a cycle was detected, since no order exists.
+    pub fn topo_positions(&self) -> HashMap<String, usize> {
+        self.sorted_order
+            .iter()
+            .enumerate()
+            .map(|(position, id)| (id.clone(), position))
+            .collect()
+    }
+}
+
 impl<T: Clone> Vertex<T> {
     /// Creates a new vertex with the given ID and value
     pub fn new(id: String, value: T) -> Self {
@@ -55,6 +80,15 @@ impl<T: Clone> Vertex<T> {
             edges: Vec::new(),
         }
     }
+
+    /// Returns the IDs of the vertices this vertex points to, sorted
+    ///
+    /// `edges` keeps insertion order; use this for output that must not depend on it.
+    pub fn sorted_edges(&self) -> Vec<String> {
+        let mut edges = self.edges.clone();
+        edges.sort();
+        edges
+    }
 }
 
 impl<T: Clone> Graph<T> {
@@ -66,10 +100,14 @@ impl<T: Clone> Graph<T> {
     }
 
     /// Adds a vertex with the given ID and value to the graph
-    pub fn add_vertex(&mut self, id: String, value: T) {
-        if !self.vertices.contains_key(&id) {
-            self.vertices.insert(id.clone(), Vertex::new(id, value));
+    ///
+    /// Returns false, leaving the existing vertex untouched, if the ID is already present.
+    pub fn add_vertex(&mut self, id: String, value: T) -> bool {
+        if self.vertices.contains_key(&id) {
+            return false;
         }
+        self.vertices.insert(id.clone(), Vertex::new(id, value));
+        true
     }
 
     /// Adds a directed edge from vertex with ID `from_id` to vertex with ID `to_id`
@@ -80,11 +118,27 @@ impl<T: Clone> Graph<T> {
         }
 
         // Get the from vertex and check if edge already exists
-        if let Some(vertex) = self.vertices.get_mut(from_id) {
-            if !vertex.edges.contains(&to_id.to_string()) {
-                vertex.edges.push(to_id.to_string());
+        if let Some(vertex) = self.vertices.get_mut(from_id)
+            && !vertex.edges.contains(&to_id.to_string())
+        {
+            vertex.edges.push(to_id.to_string());
+        }
+    }
+
+    /// Adds a directed edge like `add_edge`, but fails if either vertex does not exist
+    ///
+    /// The error names the missing vertex, checking `from_id` first.
+    pub fn add_edge_checked(&mut self, from_id: &str, to_id: &str) -> Result<(), String> {
+        for id in [from_id, to_id] {
+            if !self.vertices.contains_key(id) {
+                return Err(format!(
+                    "Cannot add edge {} -> {}: vertex '{}' does not exist",
+                    from_id, to_id, id
+                ));
             }
         }
+        self.add_edge(from_id, to_id);
+        Ok(())
     }
 
     /// Returns the vertex with the given ID if it exists
@@ -92,6 +146,25 @@ impl<T: Clone> Graph<T> {
         self.vertices.get(id)
     }
 
+    /// Returns true if a vertex with the given ID exists
+    pub fn contains_vertex(&self, id: &str) -> bool {
+        self.vertices.contains_key(id)
+    }
+
+    /// Returns the number of vertices in the graph
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the total number of distinct edges in the graph
+    pub fn edge_count(&self) -> usize {
+        // Edges are public and may have been pushed directly, so count each target once
+        self.vertices
+            .values()
+            .map(|vertex| vertex.edges.iter().collect::<HashSet<_>>().len())
+            .sum()
+    }
+
     /// Performs topological sorting of the graph
     /// Returns a SortResult containing the sorted order and cycle information
     pub fn topological_sort(&self) -> SortResult {
@@ -118,19 +191,23 @@ impl<T: Clone> Graph<T> {
             in_degree.insert(id.clone(), 0);
         }
 
-        // Calculate in-degrees
+        // Calculate in-degrees. Edges are public, so skip ones whose target is missing, e.g. after
+        // a deserialized graph or direct edits, and count each target once per vertex
         for vertex in self.vertices.values() {
-            for edge_id in &vertex.edges {
-                *in_degree.entry(edge_id.clone()).or_insert(0) += 1;
+            for edge_id in self.existing_edges(vertex) {
+                if let Some(degree) = in_degree.get_mut(&edge_id) {
+                    *degree += 1;
+                }
             }
         }
 
-        // Create a queue and enqueue vertices with in-degree 0
+        // Create a queue and enqueue vertices with in-degree 0, sorted so equal graphs sort alike
         let mut queue: Vec<String> = in_degree
             .iter()
             .filter(|(_, degree)| **degree == 0)
             .map(|(id, _)| id.clone())
             .collect();
+        queue.sort();
 
         let total_vertices = self.vertices.len();
         let mut visited = 0;
@@ -141,14 +218,14 @@ impl<T: Clone> Graph<T> {
             result.sorted_order.push(u_id.clone());
             visited += 1;
 
-            // Get the edges for this vertex
+            // Get the edges for this vertex, sorted so equal graphs sort alike
             if let Some(vertex) = self.vertices.get(&u_id) {
-                let edges = vertex.edges.clone();
+                let edges = self.existing_edges(vertex);
 
                 // Reduce in-degree of adjacent vertices
                 for edge_id in edges {
                     if let Some(degree) = in_degree.get_mut(&edge_id) {
-                        *degree -= 1;
+                        *degree = degree.saturating_sub(1);
                         if *degree == 0 {
                             queue.push(edge_id.clone());
                         }
@@ -166,6 +243,19 @@ impl<T: Clone> Graph<T> {
         result
     }
 
+    /// Returns the vertex values in topological order, or the cycle path if the graph is cyclic
+    pub fn topological_sort_values(&self) -> Result<Vec<&T>, Vec<String>> {
+        let result = self.topological_sort();
+        if result.has_cycle {
+            return Err(result.cycle_path);
+        }
+        Ok(result
+            .sorted_order
+            .iter()
+            .filter_map(|id| self.get_value(id))
+            .collect())
+    }
+
     /// Returns the value associated with the given vertex ID
     pub fn get_value(&self, id: &str) -> Option<&T> {
         self.vertices.get(id).map(|v| &v.value)
@@ -173,9 +263,7 @@ impl<T: Clone> Graph<T> {
 
     /// Returns the dependents of a given vertex
     pub fn get_dependents(&self, vertex: &Vertex<T>) -> Vec<String> {
-        let mut dependents = vertex.edges.clone();
-        dependents.sort();
-        dependents
+        vertex.sorted_edges()
     }
 
     /// Returns the dependencies of a given vertex
@@ -193,6 +281,131 @@ impl<T: Clone> Graph<T> {
         dependencies
     }
 
+    /// Returns the path of a cycle in the graph, if there is one
+    ///
+    /// The path starts and ends at the same vertex, e.g. `["A", "B", "A"]`.
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        match self.detect_cycle_with_path() {
+            (true, cycle_path) => Some(cycle_path),
+            (false, _) => None,
+        }
+    }
+
+    /// Returns how many vertices are transitively reachable from the vertex through its edges
+    ///
+    /// The vertex itself is not counted, even when it lies on a cycle. Returns 0 for a missing
+    /// vertex.
+    pub fn descendants_count(&self, id: &str) -> usize {
+        let mut descendants: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<&str> = match self.vertices.get(id) {
+            Some(vertex) => vertex.edges.iter().map(String::as_str).collect(),
+            None => return 0,
+        };
+        while let Some(next) = stack.pop() {
+            if next != id
+                && let Some(vertex) = self.vertices.get(next)
+                && descendants.insert(next)
+            {
+                stack.extend(vertex.edges.iter().map(String::as_str));
+            }
+        }
+        descendants.len()
+    }
+
+    /// Returns a copy of the part of the graph reachable from the given roots
+    ///
+    /// The roots and every vertex reachable from them through edges are cloned along with the
+    /// edges between them. Roots that do not exist are ignored.
+    pub fn subgraph(&self, roots: &[&str]) -> Graph<T> {
+        let mut reachable: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<&str> = roots.to_vec();
+        while let Some(id) = stack.pop() {
+            if let Some(vertex) = self.vertices.get(id)
+                && reachable.insert(id)
+            {
+                stack.extend(vertex.edges.iter().map(String::as_str));
+            }
+        }
+
+        let mut subgraph = Graph::new();
+        for id in reachable.iter().copied() {
+            let mut vertex = self.vertices[id].clone();
+            vertex.edges.retain(|edge_id| reachable.contains(edge_id.as_str()));
+            subgraph.vertices.insert(id.to_string(), vertex);
+        }
+        subgraph
+    }
+
+    /// Returns true if adding the edge `from_id -> to_id` would introduce a cycle
+    ///
+    /// This is the case when `to_id` can already reach `from_id`, including self-loops. Edges
+    /// referencing missing vertices would be ignored by `add_edge` and never create a cycle.
+    pub fn would_create_cycle(&self, from_id: &str, to_id: &str) -> bool {
+        if !self.vertices.contains_key(from_id) || !self.vertices.contains_key(to_id) {
+            return false;
+        }
+
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut stack = vec![to_id];
+        while let Some(id) = stack.pop() {
+            if id == from_id {
+                return true;
+            }
+            if !visited.insert(id) {
+                continue;
+            }
+            if let Some(vertex) = self.vertices.get(id) {
+                stack.extend(vertex.edges.iter().map(String::as_str));
+            }
+        }
+        false
+    }
+
+    /// Renders the graph as a Mermaid `graph TD` block
+    ///
+    /// Nodes are named `n0`, `n1`, ... in sorted id order, so ids that differ only in
+    /// punctuation or clash with Mermaid keywords such as `end` stay distinct and valid; the
+    /// original id is kept as the node label. Vertices and edges are emitted in sorted order so
+    /// the output is stable.
+    pub fn to_mermaid(&self) -> String {
+        let mut ids: Vec<&String> = self.vertices.keys().collect();
+        ids.sort();
+        let node_ids: HashMap<&str, String> = ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (id.as_str(), format!("n{}", index)))
+            .collect();
+
+        let mut output = String::from("graph TD\n");
+        for id in &ids {
+            let _ = writeln!(
+                output,
+                "    {}[\"{}\"]",
+                node_ids[id.as_str()],
+                id.replace('"', "#quot;")
+            );
+        }
+        for id in &ids {
+            for edge_id in self.get_dependents(&self.vertices[*id]) {
+                let _ = writeln!(
+                    output,
+                    "    {} --> {}",
+                    node_ids[id.as_str()],
+                    node_ids[edge_id.as_str()]
+                );
+            }
+        }
+        output
+    }
+
+    /// Returns the sorted, distinct targets of the vertex's edges that exist in the graph
+    fn existing_edges(&self, vertex: &Vertex<T>) -> Vec<String> {
+        let mut edges = vertex.sorted_edges();
+        edges.dedup();
+        edges.retain(|edge_id| self.vertices.contains_key(edge_id));
+        edges
+    }
+
     /// Performs cycle detection using DFS and returns the cycle path if found
     fn detect_cycle_with_path(&self) -> (bool, Vec<String>) {
         let mut visit_state: HashMap<String, u8> = HashMap::new();
@@ -203,12 +416,14 @@ impl<T: Clone> Graph<T> {
             visit_state.insert(id.clone(), UNVISITED);
         }
 
-        // Try DFS from each unvisited vertex
-        for id in self.vertices.keys() {
-            if *visit_state.get(id).unwrap_or(&UNVISITED) == UNVISITED {
-                if let (true, cycle_path) = self.detect_cycle(id, &mut visit_state, &mut parent) {
-                    return (true, cycle_path);
-                }
+        // Try DFS from each unvisited vertex, in sorted order so equal graphs report the same cycle
+        let mut ids: Vec<&String> = self.vertices.keys().collect();
+        ids.sort();
+        for id in ids {
+            if *visit_state.get(id).unwrap_or(&UNVISITED) == UNVISITED
+                && let (true, cycle_path) = self.detect_cycle(id, &mut visit_state, &mut parent)
+            {
+                return (true, cycle_path);
             }
         }
 
@@ -260,6 +475,13 @@ impl<T: Clone> Graph<T> {
     }
 }
 
+/// Graphs are equal when they have the same vertices and edges, regardless of insertion order
+impl<T: Clone + PartialEq> PartialEq for Graph<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vertices == other.vertices
+    }
+}
+
 impl<T: Clone> Default for Graph<T> {
     fn default() -> Self {
         Self::new()
@@ -10,7 +10,55 @@
 //       Metaform Systems, Inc. - initial API and implementation
 //
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// Without std the registry needs the spin locks
+#[cfg(not(any(feature = "std", feature = "spin")))]
+compile_error!("assemblr requires the `std` feature or, on no_std targets, the `spin` feature");
+
+/// Implements `ServiceAssemblyBase` for a struct from its declared services.
+///
+/// ```
+/// use assemblr::assembly;
+/// use assemblr::assembly::{ServiceAssemblyBase, TypeKey};
+///
+/// struct Database;
+///
+/// #[assembly(provides = [Database])]
+/// struct DatabaseAssembly {}
+///
+/// assert_eq!(DatabaseAssembly {}.provides().len(), 1);
+/// ```
+///
+/// A type listed more than once in `provides`, `requires` or `requires_optional` is a
+/// compile error:
+///
+/// ```compile_fail
+/// use assemblr::assembly;
+/// use assemblr::assembly::{ServiceAssemblyBase, TypeKey};
+///
+/// struct Database;
+///
+/// #[assembly(provides = [Database, Database])]
+/// struct DatabaseAssembly {}
+/// ```
+#[cfg(feature = "std")]
 pub use assembly_macros::assembly;
+#[cfg(feature = "std")]
 pub mod assembly;
 pub mod registry;
+#[cfg(feature = "std")]
 pub mod dag;
+mod sync;
+mod type_key;
+
+pub use type_key::TypeKey;
+
+/// Paths used by the exported macros, which must also expand in `no_std` crates
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::boxed::Box;
+    pub use alloc::sync::Arc;
+}
@@ -11,9 +11,17 @@
 
 #![allow(dead_code)]
 
-use std::any::{Any, TypeId};
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::fmt::Debug;
+use thiserror::Error;
+
+use crate::TypeKey;
+use crate::sync::{HashMap, Mutex, RwLock};
 
 /// Register a trait object: `register_trait!(registry_handle, dyn MyTrait, MyImpl)`
 /// Creates Arc<Box<dyn Trait>> automatically
@@ -23,9 +31,9 @@ use std::sync::{Arc, RwLock};
 #[macro_export]
 macro_rules! register_trait {
     ($registry:expr, $trait_type:ty, $instance:expr) => {{
-        let __registry: &RegistryWriteHandle = $registry;
-        __registry.register::<Box<$trait_type>>(std::sync::Arc::new(
-            Box::new($instance) as Box<$trait_type>
+        let __registry: &$crate::registry::RegistryWriteHandle = $registry;
+        __registry.register::<$crate::__private::Box<$trait_type>>($crate::__private::Arc::new(
+            $crate::__private::Box::new($instance) as $crate::__private::Box<$trait_type>
         ))
     }};
 }
@@ -34,10 +42,40 @@ macro_rules! register_trait {
 ///
 /// # Parameters
 /// * `registry` - A reference to `ServiceRegistry` or `RegistryWriteHandle`
+///
+/// # Panics
+/// Panics with the trait's name if no implementation is registered
 #[macro_export]
 macro_rules! resolve_trait {
     ($registry:expr, $trait_type:ty) => {{
-        ($registry).resolve::<Box<$trait_type>>()
+        ($registry).try_resolve::<$crate::__private::Box<$trait_type>>().unwrap_or_else(|| {
+            panic!(
+                "Trait object '{}' not found in registry",
+                ::core::any::type_name::<$trait_type>()
+            )
+        })
+    }};
+}
+
+/// Resolve a trait object if registered: `try_resolve_trait!(registry, dyn MyTrait)`
+///
+/// # Parameters
+/// * `registry` - A reference to `ServiceRegistry` or `RegistryWriteHandle`
+#[macro_export]
+macro_rules! try_resolve_trait {
+    ($registry:expr, $trait_type:ty) => {{
+        ($registry).try_resolve::<$crate::__private::Box<$trait_type>>()
+    }};
+}
+
+/// Resolve every trait object in a collection: `resolve_collection_trait!(registry, dyn MyTrait)`
+///
+/// # Parameters
+/// * `registry` - A reference to `ServiceRegistry` or `RegistryWriteHandle`
+#[macro_export]
+macro_rules! resolve_collection_trait {
+    ($registry:expr, $trait_type:ty) => {{
+        ($registry).resolve_collection::<$crate::__private::Box<$trait_type>>()
     }};
 }
 
@@ -49,28 +87,267 @@ macro_rules! resolve_trait {
 #[macro_export]
 macro_rules! register {
     ($registry:expr, $instance:expr) => {{
-        let __registry: &RegistryWriteHandle = $registry;
-        __registry.register(std::sync::Arc::new($instance))
+        let __registry: &$crate::registry::RegistryWriteHandle = $registry;
+        __registry.register($crate::__private::Arc::new($instance))
     }};
 }
 
+/// Formats a stored service for `ServiceRegistry::describe_all`
+type Describer = fn(&(dyn Any + Send + Sync)) -> String;
+
+/// Releases resources held by a service, e.g. files or sockets, when the registry is cleared
+///
+/// Services are shared through `Arc`, so `Drop` only runs once every resolver releases them.
+/// Registering with `RegistryWriteHandle::register_with_cleanup` lets
+/// `ServiceRegistry::clear_with_cleanup` release resources regardless.
+pub trait Cleanup {
+    fn cleanup(&self);
+}
+
+/// Invokes `Cleanup::cleanup` on a stored service for `ServiceRegistry::clear_with_cleanup`
+type Cleaner = fn(&(dyn Any + Send + Sync));
+
+/// A registered service instance together with the name of its type
+#[derive(Clone)]
+struct ServiceEntry {
+    type_name: &'static str,
+    service: Arc<dyn Any + Send + Sync>,
+    describer: Option<Describer>,
+    cleaner: Option<Cleaner>,
+}
+
+impl ServiceEntry {
+    fn new<T: Any + Send + Sync + 'static>(service: Arc<T>) -> Self {
+        ServiceEntry {
+            type_name: core::any::type_name::<T>(),
+            service: service as Arc<dyn Any + Send + Sync>,
+            describer: None,
+            cleaner: None,
+        }
+    }
+
+    fn describable<T: Any + Send + Sync + Debug + 'static>(service: Arc<T>) -> Self {
+        ServiceEntry {
+            describer: Some(describe::<T>),
+            ..ServiceEntry::new(service)
+        }
+    }
+}
+
+fn clean_up<T: Cleanup + 'static>(service: &(dyn Any + Send + Sync)) {
+    if let Some(service) = service.downcast_ref::<T>() {
+        service.cleanup();
+    }
+}
+
+fn describe<T: Debug + 'static>(service: &(dyn Any + Send + Sync)) -> String {
+    service
+        .downcast_ref::<T>()
+        .map(|s| format!("{:?}", s))
+        .unwrap_or_default()
+}
+
+type ServiceMap = HashMap<TypeId, ServiceEntry>;
+
+/// Storage for registered instances, shared by a registry and its write handles
+///
+/// With the `arc-swap` feature reads load an immutable map without locking, and each write
+/// swaps in an updated copy. Writers are serialized so concurrent registrations are not lost.
+struct ServiceStore {
+    #[cfg(not(feature = "arc-swap"))]
+    map: RwLock<ServiceMap>,
+    #[cfg(feature = "arc-swap")]
+    map: arc_swap::ArcSwap<ServiceMap>,
+    #[cfg(feature = "arc-swap")]
+    writer: Mutex<()>,
+}
+
+impl ServiceStore {
+    #[cfg(not(feature = "arc-swap"))]
+    fn with_capacity(capacity: usize) -> Self {
+        ServiceStore {
+            map: RwLock::new(HashMap::with_capacity(capacity)),
+        }
+    }
+
+    #[cfg(feature = "arc-swap")]
+    fn with_capacity(capacity: usize) -> Self {
+        ServiceStore {
+            map: arc_swap::ArcSwap::from_pointee(HashMap::with_capacity(capacity)),
+            writer: Mutex::new(()),
+        }
+    }
+
+    #[cfg(not(feature = "arc-swap"))]
+    fn read<R>(&self, f: impl FnOnce(&ServiceMap) -> R) -> R {
+        f(&self.map.read())
+    }
+
+    #[cfg(feature = "arc-swap")]
+    fn read<R>(&self, f: impl FnOnce(&ServiceMap) -> R) -> R {
+        f(&self.map.load())
+    }
+
+    #[cfg(not(feature = "arc-swap"))]
+    fn write<R>(&self, f: impl FnOnce(&mut ServiceMap) -> R) -> R {
+        f(&mut self.map.write())
+    }
+
+    #[cfg(feature = "arc-swap")]
+    fn write<R>(&self, f: impl FnOnce(&mut ServiceMap) -> R) -> R {
+        let _writer = self.writer.lock();
+        let mut map = ServiceMap::clone(&self.map.load());
+        let result = f(&mut map);
+        self.map.store(Arc::new(map));
+        result
+    }
+
+    /// Get the instance stored under the key as a `T`
+    fn get<T: Any + Send + Sync + 'static>(&self, key: &TypeId) -> Option<Arc<T>> {
+        self.read(|services| {
+            services
+                .get(key)
+                .and_then(|entry| entry.service.clone().downcast::<T>().ok())
+        })
+    }
+
+    fn insert(&self, key: TypeId, entry: ServiceEntry) {
+        self.write(|services| services.insert(key, entry));
+    }
+
+    fn contains_key(&self, key: &TypeId) -> bool {
+        self.read(|services| services.contains_key(key))
+    }
+}
+
+/// Error returned by fallible service factories
+pub type BoxError = Box<dyn core::error::Error + Send + Sync>;
+
+/// Errors returned by strict registrations
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    #[error("Service '{type_name}' is already registered")]
+    AlreadyRegistered { type_name: String },
+}
+
+/// Builds a service on first resolution; failures are returned to the caller and not cached
+type Factory = Arc<dyn Fn() -> Result<ServiceEntry, BoxError> + Send + Sync>;
+
+type FactoryMap = HashMap<TypeId, Factory>;
+
+/// Services contributed to multi-binding collections, in registration order
+type CollectionMap = HashMap<TypeId, Vec<ServiceEntry>>;
+
+fn resolve_collection_from<T: Any + Send + Sync + 'static>(
+    collections: &RwLock<CollectionMap>,
+) -> Vec<Arc<T>> {
+    collections
+        .read()
+        .get(&TypeId::of::<T>())
+        .map(|entries| entries.iter().filter_map(|entry| downcast::<T>(entry).ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Resolves `T` from the instances, running and caching its factory if it has not been built yet
+fn try_resolve_from<T: Any + Send + Sync + 'static>(
+    services: &ServiceStore,
+    factories: &RwLock<FactoryMap>,
+) -> Result<Arc<T>, BoxError> {
+    let key = TypeId::of::<T>();
+    if let Some(found) = services.read(|services| services.get(&key).map(downcast::<T>)) {
+        return found;
+    }
+
+    // Run the factory without holding any lock so it may resolve other services
+    let factory = factories.read().get(&key).cloned().ok_or_else(|| {
+        format!("Service '{}' not found in registry", core::any::type_name::<T>())
+    })?;
+    let entry = factory()?;
+    let service = downcast::<T>(&entry)?;
+    services.insert(key, entry);
+    factories.write().remove(&key);
+    Ok(service)
+}
+
+/// Registers `T` unless an instance or a factory is already registered for it
+fn register_once_into<T: Any + Send + Sync + 'static>(
+    services: &ServiceStore,
+    factories: &RwLock<FactoryMap>,
+    service: Arc<T>,
+) -> Result<(), RegistryError> {
+    let key = TypeId::of::<T>();
+    // Take the factories lock inside the services lock, in the same order as `extend`
+    let inserted = services.write(|services| {
+        if services.contains_key(&key) || factories.read().contains_key(&key) {
+            return false;
+        }
+        services.insert(key, ServiceEntry::new(service));
+        true
+    });
+    if !inserted {
+        return Err(RegistryError::AlreadyRegistered {
+            type_name: core::any::type_name::<T>().to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Resolves the `T` stored under `key`, which differs from `T`'s own id for interface views
+fn resolve_keyed<T: Any + Send + Sync + 'static>(services: &ServiceStore, key: TypeId) -> Arc<T> {
+    services
+        .get::<T>(&key)
+        .unwrap_or_else(|| panic!("Service '{}' not found in registry", core::any::type_name::<T>()))
+}
+
+fn downcast<T: Any + Send + Sync + 'static>(entry: &ServiceEntry) -> Result<Arc<T>, BoxError> {
+    entry.service.clone().downcast::<T>().map_err(|_| {
+        format!("Service '{}' has an unexpected type", core::any::type_name::<T>()).into()
+    })
+}
+
+/// A point-in-time copy of a registry's services, created by `ServiceRegistry::snapshot`
+///
+/// Instances are shared with the registry, so taking a snapshot only clones `Arc`s.
+#[derive(Clone)]
+pub struct RegistrySnapshot {
+    services: ServiceMap,
+    factories: FactoryMap,
+    collections: CollectionMap,
+}
+
 /// A registry that maps service types to their instances
 pub struct ServiceRegistry {
-    services: Arc<RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>,
+    services: Arc<ServiceStore>,
+    factories: Arc<RwLock<FactoryMap>>,
+    collections: Arc<RwLock<CollectionMap>>,
 }
 
 impl ServiceRegistry {
     /// Create a new empty registry
     pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new empty registry with room for at least `capacity` services
+    ///
+    /// Pre-sizing avoids repeatedly growing the registry while many services are registered.
+    pub fn with_capacity(capacity: usize) -> Self {
         ServiceRegistry {
-            services: Arc::new(RwLock::new(HashMap::new())),
+            services: Arc::new(ServiceStore::with_capacity(capacity)),
+            factories: Arc::new(RwLock::new(HashMap::new())),
+            collections: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    /// Make room for at least `additional` more services
+    pub(crate) fn reserve(&self, additional: usize) {
+        self.services.write(|services| services.reserve(additional));
+    }
+
     /// Register a service
     pub(crate) fn register<T: Any + Send + Sync + 'static>(&self, service: Arc<T>) {
-        let mut services = self.services.write().unwrap();
-        services.insert(TypeId::of::<T>(), service as Arc<dyn Any + Send + Sync>);
+        self.factories.write().remove(&TypeId::of::<T>());
+        self.services.insert(TypeId::of::<T>(), ServiceEntry::new(service));
     }
 
     /// Get a registered service
@@ -78,44 +355,344 @@ impl ServiceRegistry {
     /// # Panics
     /// Panics if the service is not registered
     pub fn resolve<T: Any + Send + Sync + 'static>(&self) -> Arc<T> {
-        let services = self.services.read().unwrap();
-        services
-            .get(&TypeId::of::<T>())
-            .and_then(|service| service.clone().downcast::<T>().ok())
-            .unwrap_or_else(|| panic!("Service '{}' not found in registry", std::any::type_name::<T>()))
+        resolve_keyed::<T>(&self.services, TypeId::of::<T>())
     }
 
-    /// Check if a service is registered
+    /// Register a service, failing if the type is already registered
+    ///
+    /// Unlike `RegistryWriteHandle::register`, which replaces an existing registration, the
+    /// registry is left unchanged on error. A pending factory counts as a registration.
+    pub fn register_once<T: Any + Send + Sync + 'static>(&self, service: Arc<T>) -> Result<(), RegistryError> {
+        register_once_into(&self.services, &self.factories, service)
+    }
+
+    /// Get a registered service, or `None` if it is not registered
+    pub fn try_resolve<T: Any + Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.services.get::<T>(&TypeId::of::<T>())
+    }
+
+    /// Get the stored instance for a type without naming it, e.g. for generic inspectors
+    ///
+    /// Callers downcast the returned value themselves. Factories that have not been built yet
+    /// are not run.
+    pub fn get_dyn(&self, id: &TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.services
+            .read(|services| services.get(id).map(|entry| entry.service.clone()))
+    }
+
+    /// Register a service under an interface marker type in addition to any other registrations
+    ///
+    /// The same instance can be registered under several markers and resolved through each of
+    /// them with `resolve_as`.
+    pub fn register_as<Iface: 'static, Concrete: Any + Send + Sync + 'static>(
+        &self,
+        service: Arc<Concrete>,
+    ) {
+        self.factories.write().remove(&TypeId::of::<Iface>());
+        self.services.insert(TypeId::of::<Iface>(), ServiceEntry::new(service));
+    }
+
+    /// Get a service registered under an interface marker type with `register_as`
+    ///
+    /// # Panics
+    /// Panics if nothing is registered under `Iface` or it is not a `Concrete`
+    pub fn resolve_as<Iface: 'static, Concrete: Any + Send + Sync + 'static>(&self) -> Arc<Concrete> {
+        resolve_keyed::<Concrete>(&self.services, TypeId::of::<Iface>())
+    }
+
+    /// Get a service, building it with its registered factory on first use
+    ///
+    /// A successfully built service is cached; a construction error is returned and the factory
+    /// is kept so a later call, or a re-registered factory, can try again.
+    pub fn try_resolve_factory<T: Any + Send + Sync + 'static>(&self) -> Result<Arc<T>, BoxError> {
+        try_resolve_from::<T>(&self.services, &self.factories)
+    }
+
+    /// Get every service contributed to the `T` collection, in registration order
+    pub fn resolve_collection<T: Any + Send + Sync + 'static>(&self) -> Vec<Arc<T>> {
+        resolve_collection_from::<T>(&self.collections)
+    }
+
+    /// Check if a service is registered, either as an instance or as a factory
     pub fn contains<T: Any + 'static>(&self) -> bool {
+        let key = TypeId::of::<T>();
+        self.services.contains_key(&key)
+            || self.factories.read().contains_key(&key)
+    }
+
+    /// Check if a service is registered for the given key, either as an instance or as a factory
+    pub fn contains_key(&self, key: &TypeKey) -> bool {
+        self.services.contains_key(&key.type_id())
+            || self.factories.read().contains_key(&key.type_id())
+    }
+
+    /// Copy every service, factory and collection contribution from another registry
+    ///
+    /// Instances are shared, not cloned. A service or factory in `other` replaces whatever is
+    /// registered for the same type, while collection contributions are appended.
+    pub fn extend(&self, other: &ServiceRegistry) {
+        if Arc::ptr_eq(&self.services, &other.services) {
+            return;
+        }
+        let services = other.services.read(ServiceMap::clone);
+        let factories = other.factories.read().clone();
+        let collections = other.collections.read().clone();
+
+        // Keep each type registered as either an instance or a factory, never both
+        self.services.write(|own_services| {
+            let mut own_factories = self.factories.write();
+            for (key, entry) in services {
+                own_factories.remove(&key);
+                own_services.insert(key, entry);
+            }
+            for (key, factory) in factories {
+                own_services.remove(&key);
+                own_factories.insert(key, factory);
+            }
+        });
+        let mut own_collections = self.collections.write();
+        for (key, entries) in collections {
+            own_collections.entry(key).or_default().extend(entries);
+        }
+    }
+
+    /// Remove the service or factory registered for the given key
+    pub(crate) fn remove(&self, key: &TypeKey) {
+        self.services.write(|services| services.remove(&key.type_id()));
+        self.factories.write().remove(&key.type_id());
+    }
+
+    /// Remove all registered services, factories and collections
+    pub fn clear(&self) {
+        self.services.write(|services| services.clear());
+        self.factories.write().clear();
+        self.collections.write().clear();
+    }
+
+    /// Clean up every service registered with a cleanup hook, then remove everything
+    pub fn clear_with_cleanup(&self) {
+        let services = self.services.write(core::mem::take);
+        // Run hooks without holding the lock so they may use the registry
+        for entry in services.values() {
+            if let Some(cleaner) = entry.cleaner {
+                cleaner(entry.service.as_ref());
+            }
+        }
+        self.clear();
+    }
+
+    /// Capture the currently registered services, factories and collections
+    pub fn snapshot(&self) -> RegistrySnapshot {
+        RegistrySnapshot {
+            services: self.services.read(ServiceMap::clone),
+            factories: self.factories.read().clone(),
+            collections: self.collections.read().clone(),
+        }
+    }
+
+    /// Replace the registered services, factories and collections with those captured in the snapshot
+    ///
+    /// Everything registered after the snapshot was taken is removed.
+    pub fn restore(&self, snapshot: RegistrySnapshot) {
+        self.services.write(|services| *services = snapshot.services);
+        *self.factories.write() = snapshot.factories;
+        *self.collections.write() = snapshot.collections;
+    }
+
+    /// Get the type name of the service registered for the given key, if any
+    pub fn resolve_name(&self, key: &TypeKey) -> Option<String> {
         self.services
-            .read()
-            .unwrap()
-            .contains_key(&TypeId::of::<T>())
+            .read(|services| services.get(&key.type_id()).map(|entry| entry.type_name.to_string()))
+    }
+
+    /// Get every registered instance whose type name starts with the prefix, e.g. a module path
+    ///
+    /// Entries are paired with their type name and sorted by it. Factories that have not been
+    /// built yet and collection contributions are not included.
+    pub fn resolve_by_name_prefix(&self, prefix: &str) -> Vec<(String, Arc<dyn Any + Send + Sync>)> {
+        let mut matches: Vec<(String, Arc<dyn Any + Send + Sync>)> = self.services.read(|services| {
+            services
+                .values()
+                .filter(|entry| entry.type_name.starts_with(prefix))
+                .map(|entry| (entry.type_name.to_string(), entry.service.clone()))
+                .collect()
+        });
+        matches.sort_by(|a, b| a.0.cmp(&b.0));
+        matches
+    }
+
+    /// Get the type name and `Debug` representation of every service registered describably
+    ///
+    /// Entries are sorted by type name.
+    pub fn describe_all(&self) -> Vec<(String, String)> {
+        let mut descriptions: Vec<(String, String)> = self.services.read(|services| {
+            services
+                .values()
+                .filter_map(|entry| {
+                    entry
+                        .describer
+                        .map(|describer| (entry.type_name.to_string(), describer(entry.service.as_ref())))
+                })
+                .collect()
+        });
+        descriptions.sort();
+        descriptions
     }
 }
 
+/// Callback invoked with the type name of each service registered through a handle
+pub type RegisterObserver = Arc<dyn Fn(&str) + Send + Sync>;
+
 pub struct RegistryWriteHandle {
-    services: Arc<RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>,
+    services: Arc<ServiceStore>,
+    factories: Arc<RwLock<FactoryMap>>,
+    collections: Arc<RwLock<CollectionMap>>,
+    observer: Option<RegisterObserver>,
+    /// Types written through this handle, in the order they were first registered
+    written: Mutex<Vec<TypeId>>,
 }
 
 impl RegistryWriteHandle {
     pub fn new(registry: &ServiceRegistry) -> Self {
         RegistryWriteHandle {
             services: Arc::clone(&registry.services),
+            factories: Arc::clone(&registry.factories),
+            collections: Arc::clone(&registry.collections),
+            observer: None,
+            written: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Creates a handle that notifies the observer after every registration
+    pub fn with_observer(registry: &ServiceRegistry, observer: RegisterObserver) -> Self {
+        RegistryWriteHandle {
+            services: Arc::clone(&registry.services),
+            factories: Arc::clone(&registry.factories),
+            collections: Arc::clone(&registry.collections),
+            observer: Some(observer),
+            written: Mutex::new(Vec::new()),
         }
     }
 
     pub fn register<T: Any + Send + Sync + 'static>(&self, service: Arc<T>) {
-        let mut services = self.services.write().unwrap();
-        services.insert(TypeId::of::<T>(), service as Arc<dyn Any + Send + Sync>);
+        self.insert::<T>(ServiceEntry::new(service));
+    }
+
+    /// Register a service, failing if the type is already registered, see `ServiceRegistry::register_once`
+    pub fn register_once<T: Any + Send + Sync + 'static>(&self, service: Arc<T>) -> Result<(), RegistryError> {
+        register_once_into(&self.services, &self.factories, service)?;
+        self.notify::<T>();
+        Ok(())
+    }
+
+    /// Register a service whose `Debug` representation is included in `describe_all`
+    pub fn register_describable<T: Any + Send + Sync + Debug + 'static>(&self, service: Arc<T>) {
+        self.insert::<T>(ServiceEntry::describable(service));
+    }
+
+    /// Register a service under an interface marker type, see `ServiceRegistry::register_as`
+    pub fn register_as<Iface: 'static, Concrete: Any + Send + Sync + 'static>(
+        &self,
+        service: Arc<Concrete>,
+    ) {
+        self.insert::<Iface>(ServiceEntry::new(service));
+    }
+
+    /// Get a service registered under an interface marker type with `register_as`
+    pub fn resolve_as<Iface: 'static, Concrete: Any + Send + Sync + 'static>(&self) -> Arc<Concrete> {
+        resolve_keyed::<Concrete>(&self.services, TypeId::of::<Iface>())
+    }
+
+    /// Register a service whose `Cleanup` hook runs on `ServiceRegistry::clear_with_cleanup`
+    pub fn register_with_cleanup<T: Any + Send + Sync + Cleanup + 'static>(&self, service: Arc<T>) {
+        self.insert::<T>(ServiceEntry {
+            cleaner: Some(clean_up::<T>),
+            ..ServiceEntry::new(service)
+        });
+    }
+
+    /// Register a factory that builds the service on first `try_resolve_factory`
+    ///
+    /// Replaces any instance or factory previously registered for `T`.
+    pub fn register_try_factory<T, F>(&self, factory: F)
+    where
+        T: Any + Send + Sync + 'static,
+        F: Fn() -> Result<Arc<T>, BoxError> + Send + Sync + 'static,
+    {
+        let factory: Factory = Arc::new(move || factory().map(ServiceEntry::new));
+        self.services.write(|services| services.remove(&TypeId::of::<T>()));
+        self.factories
+            .write()
+            .insert(TypeId::of::<T>(), factory);
+        self.notify::<T>();
+    }
+
+    /// Get a service, building it with its registered factory on first use
+    pub fn try_resolve_factory<T: Any + Send + Sync + 'static>(&self) -> Result<Arc<T>, BoxError> {
+        try_resolve_from::<T>(&self.services, &self.factories)
+    }
+
+    /// Add a service to the `T` collection, keeping services contributed earlier
+    pub fn register_collection<T: Any + Send + Sync + 'static>(&self, service: Arc<T>) {
+        self.collections
+            .write()
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(ServiceEntry::new(service));
+        self.notify::<T>();
+    }
+
+    /// Get every service contributed to the `T` collection, in registration order
+    pub fn resolve_collection<T: Any + Send + Sync + 'static>(&self) -> Vec<Arc<T>> {
+        resolve_collection_from::<T>(&self.collections)
     }
 
     pub fn resolve<T: Any + Send + Sync + 'static>(&self) -> Arc<T> {
-        let services = self.services.read().unwrap();
-        services
-            .get(&TypeId::of::<T>())
-            .and_then(|service| service.clone().downcast::<T>().ok())
-            .unwrap_or_else(|| panic!("Service '{}' not found in registry", std::any::type_name::<T>()))
+        resolve_keyed::<T>(&self.services, TypeId::of::<T>())
+    }
+
+    pub fn try_resolve<T: Any + Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.services.get::<T>(&TypeId::of::<T>())
+    }
+
+    /// Returns the types registered through this handle, in the order they were first registered
+    ///
+    /// Services registered into the shared storage by other handles, including those registered
+    /// before this handle was created, are not included.
+    pub fn was_registered_here(&self) -> Vec<TypeId> {
+        self.written.lock().clone()
+    }
+
+    /// Returns a registry backed by the same storage this handle writes to
+    ///
+    /// The registry is not read-only: its `clear`, `restore`, `extend`, `register_once` and
+    /// `register_as` change the shared storage, and registrations made through it are not
+    /// reported to this handle's observer or `was_registered_here`.
+    pub fn registry_arc(&self) -> Arc<ServiceRegistry> {
+        Arc::new(ServiceRegistry {
+            services: Arc::clone(&self.services),
+            factories: Arc::clone(&self.factories),
+            collections: Arc::clone(&self.collections),
+        })
+    }
+
+    fn insert<T: 'static>(&self, entry: ServiceEntry) {
+        // An instance supersedes a factory that has not been built yet
+        self.factories.write().remove(&TypeId::of::<T>());
+        self.services.insert(TypeId::of::<T>(), entry);
+        self.notify::<T>();
+    }
+
+    fn notify<T: 'static>(&self) {
+        {
+            let mut written = self.written.lock();
+            if !written.contains(&TypeId::of::<T>()) {
+                written.push(TypeId::of::<T>());
+            }
+        }
+        if let Some(observer) = &self.observer {
+            observer(core::any::type_name::<T>());
+        }
     }
 }
 
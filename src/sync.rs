@@ -0,0 +1,62 @@
+// Copyright (c) 2025 Metaform Systems, Inc
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Contributors:
+//      Metaform Systems, Inc. - initial API and implementation
+
+//! Locks and maps used by the registry
+//!
+//! By default these come from `std`. The `spin` feature switches to spin locks and `hashbrown`,
+//! which only need `core` and `alloc`. The `std` locks panic when poisoned; spin locks cannot
+//! be poisoned.
+
+#[cfg(not(feature = "spin"))]
+pub(crate) use std::collections::HashMap;
+
+#[cfg(feature = "spin")]
+pub(crate) use hashbrown::HashMap;
+
+#[cfg(not(feature = "spin"))]
+mod locks {
+    use std::sync::{MutexGuard, RwLockReadGuard, RwLockWriteGuard};
+
+    pub(crate) struct RwLock<T>(std::sync::RwLock<T>);
+
+    impl<T> RwLock<T> {
+        pub(crate) const fn new(value: T) -> Self {
+            RwLock(std::sync::RwLock::new(value))
+        }
+
+        pub(crate) fn read(&self) -> RwLockReadGuard<'_, T> {
+            self.0.read().unwrap()
+        }
+
+        pub(crate) fn write(&self) -> RwLockWriteGuard<'_, T> {
+            self.0.write().unwrap()
+        }
+    }
+
+    pub(crate) struct Mutex<T>(std::sync::Mutex<T>);
+
+    impl<T> Mutex<T> {
+        pub(crate) const fn new(value: T) -> Self {
+            Mutex(std::sync::Mutex::new(value))
+        }
+
+        pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+            self.0.lock().unwrap()
+        }
+    }
+}
+
+#[cfg(feature = "spin")]
+mod locks {
+    pub(crate) use spin::{Mutex, RwLock};
+}
+
+pub(crate) use locks::{Mutex, RwLock};
@@ -0,0 +1,78 @@
+// Copyright (c) 2025 Metaform Systems, Inc
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Contributors:
+//      Metaform Systems, Inc. - initial API and implementation
+
+//! Keys identifying services by type
+
+use alloc::string::{String, ToString};
+use core::any::TypeId;
+use core::cmp::Ordering;
+use core::fmt;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct TypeKey(TypeId, String);
+
+/// Stands in for the type of services only known by name, see `TypeKey::from_name`
+struct DynamicService;
+
+impl TypeKey {
+    pub fn new<T: 'static>() -> Self {
+        TypeKey(TypeId::of::<T>(), String::from(core::any::type_name::<T>()))
+    }
+
+    /// Creates a key from an existing `TypeId` and a name, e.g. for types registered at runtime
+    pub fn from_parts(id: TypeId, name: String) -> Self {
+        TypeKey(id, name)
+    }
+
+    /// Creates a key identified only by its name, for services without a Rust type at compile time
+    ///
+    /// Keys created from the same name are equal, so dynamically loaded plugins can provide and
+    /// require them in the dependency graph. All such keys share one `TypeId`, so they cannot key
+    /// services in a `ServiceRegistry` and are skipped when checking that provisions are registered.
+    pub fn from_name(name: &str) -> Self {
+        TypeKey(TypeId::of::<DynamicService>(), name.to_string())
+    }
+
+    /// Returns true if the key was created with `from_name`
+    pub fn is_dynamic(&self) -> bool {
+        self.0 == TypeId::of::<DynamicService>()
+    }
+
+    /// Returns the identifier of the keyed type
+    pub fn type_id(&self) -> TypeId {
+        self.0
+    }
+
+    /// Returns the human-readable name of the keyed type
+    pub fn type_name(&self) -> &str {
+        &self.1
+    }
+}
+
+/// Orders keys by type name, which unlike `TypeId` is stable across runs
+impl Ord for TypeKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Fall back to the TypeId so distinct types sharing a name stay consistent with Eq
+        self.1.cmp(&other.1).then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for TypeKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for TypeKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.1)
+    }
+}
@@ -10,13 +10,17 @@
 //       Metaform Systems, Inc. - initial API and implementation
 //
 
+#![cfg(feature = "std")]
+
 use assemblr::assembly::{
-    Assembler, AssemblyContext, AssemblyError, LogMonitor, MutableAssemblyContext, NoopMonitor,
-    Result, RuntimeMode, ServiceAssembly, ServiceAssemblyBase, TypeKey,
+    Assembler, AssemblyContext, AssemblyError, CapturingMonitor, DuplicatePolicy, FilteringMonitor,
+    Level, LifecycleObserver, LogMonitor, MutableAssemblyContext, NoopMonitor, Phase, Result,
+    RuntimeMode, ServiceAssembly, ServiceAssemblyBase, TypeKey,
 };
-use assemblr::registry::ServiceRegistry;
+use assemblr::registry::{RegistryWriteHandle, ServiceRegistry};
 use assembly_macros::assembly;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 // ============================================================================
 // Test Service Types
 // ============================================================================
@@ -53,9 +57,12 @@ impl Cache for RedisCache {
 // Mock Assembly (for flexible testing)
 // ============================================================================
 
+type Registrar = Box<dyn Fn(&RegistryWriteHandle) + Send + Sync>;
+
 struct MockServiceAssembly {
     name: String,
     provides: Vec<TypeKey>,
+    registrars: Vec<Registrar>,
     requires: Vec<TypeKey>,
 }
 
@@ -64,12 +71,17 @@ impl MockServiceAssembly {
         Self {
             name: name.to_string(),
             provides: Vec::new(),
+            registrars: Vec::new(),
             requires: Vec::new(),
         }
     }
 
-    fn with_provides(mut self, services: Vec<TypeKey>) -> Self {
-        self.provides = services;
+    /// Declares the service as provided and registers the instance during init
+    fn provide<T: Send + Sync + 'static>(mut self, service: T) -> Self {
+        let service = Arc::new(service);
+        self.provides.push(TypeKey::new::<T>());
+        self.registrars
+            .push(Box::new(move |registry| registry.register(service.clone())));
         self
     }
 
@@ -94,7 +106,10 @@ impl ServiceAssemblyBase for MockServiceAssembly {
 }
 
 impl ServiceAssembly for MockServiceAssembly {
-    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        for registrar in &self.registrars {
+            registrar(&context.registry);
+        }
         Ok(())
     }
 }
@@ -109,9 +124,9 @@ fn test_single_assembly_no_dependencies() {
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
     let mock = Arc::new(
-        MockServiceAssembly::new("CoreAssembly").with_provides(vec![TypeKey::new::<ServiceA>()]),
+        MockServiceAssembly::new("CoreAssembly").provide(ServiceA),
     );
-    assembler.register(mock);
+    assembler.register(mock).unwrap();
 
     assert!(assembler.assemble().is_ok());
 }
@@ -122,18 +137,18 @@ fn test_assembly_with_single_dependency() {
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
     let provider = Arc::new(
-        MockServiceAssembly::new("Provider").with_provides(vec![TypeKey::new::<ServiceA>()]),
+        MockServiceAssembly::new("Provider").provide(ServiceA),
     );
 
     let consumer = Arc::new(
         MockServiceAssembly::new("Consumer")
-            .with_provides(vec![TypeKey::new::<ServiceB>()])
+            .provide(ServiceB)
             .with_requires(vec![TypeKey::new::<ServiceA>()]),
     );
 
     // Register in reverse order to test dependency resolution
-    assembler.register(consumer);
-    assembler.register(provider);
+    assembler.register(consumer).unwrap();
+    assembler.register(provider).unwrap();
 
     assert!(assembler.assemble().is_ok());
 }
@@ -147,7 +162,7 @@ fn test_missing_dependency_fails() {
         MockServiceAssembly::new("NeedsDependency").with_requires(vec![TypeKey::new::<ServiceA>()]),
     );
 
-    assembler.register(mock);
+    assembler.register(mock).unwrap();
 
     let result = assembler.assemble();
     assert!(result.is_err());
@@ -159,6 +174,31 @@ fn test_missing_dependency_fails() {
     );
 }
 
+#[test]
+fn test_all_missing_dependencies_reported_together() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("NeedsA").with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("NeedsB").with_requires(vec![TypeKey::new::<ServiceB>()]),
+    )).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    let AssemblyError::MissingDependencies(missing) = &err else {
+        panic!("Unexpected error: {}", err);
+    };
+    assert_eq!(missing.len(), 2);
+    assert!(missing.contains(&("NeedsA".to_string(), TypeKey::new::<ServiceA>())));
+    assert!(missing.contains(&("NeedsB".to_string(), TypeKey::new::<ServiceB>())));
+
+    let msg = err.to_string();
+    assert!(msg.contains("NeedsA requires"));
+    assert!(msg.contains("NeedsB requires"));
+}
+
 #[test]
 fn test_cyclic_dependency_detected() {
     let monitor = Arc::new(NoopMonitor);
@@ -166,18 +206,18 @@ fn test_cyclic_dependency_detected() {
 
     let assembly1 = Arc::new(
         MockServiceAssembly::new("Assembly1")
-            .with_provides(vec![TypeKey::new::<ServiceA>()])
+            .provide(ServiceA)
             .with_requires(vec![TypeKey::new::<ServiceB>()]),
     );
 
     let assembly2 = Arc::new(
         MockServiceAssembly::new("Assembly2")
-            .with_provides(vec![TypeKey::new::<ServiceB>()])
+            .provide(ServiceB)
             .with_requires(vec![TypeKey::new::<ServiceA>()]),
     );
 
-    assembler.register(assembly1);
-    assembler.register(assembly2);
+    assembler.register(assembly1).unwrap();
+    assembler.register(assembly2).unwrap();
 
     let result = assembler.assemble();
     assert!(result.is_err());
@@ -189,6 +229,136 @@ fn test_cyclic_dependency_detected() {
     );
 }
 
+#[test]
+fn test_cyclic_dependency_shows_arrow_chain() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Assembly1")
+            .provide(ServiceA)
+            .with_requires(vec![TypeKey::new::<ServiceB>()]),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Assembly2")
+            .provide(ServiceB)
+            .with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    assert!(matches!(&err, AssemblyError::CyclicDependency { cycle, .. }
+        if cycle == "Assembly1 -> Assembly2 -> Assembly1"));
+    assert!(err.to_string().contains("(Assembly1 -> Assembly2 -> Assembly1)"));
+}
+
+#[test]
+fn test_cyclic_dependency_lists_services() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Assembly1")
+            .provide(ServiceA)
+            .with_requires(vec![TypeKey::new::<ServiceC>(), TypeKey::new::<ServiceB>()]),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Assembly2")
+            .provide(ServiceB)
+            .with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+    assembler.register(Arc::new(MockServiceAssembly::new("Assembly3").provide(ServiceC))).unwrap();
+
+    match assembler.assemble() {
+        Err(AssemblyError::CyclicDependency { cycle, services }) => {
+            assert_eq!(cycle, "Assembly1 -> Assembly2 -> Assembly1");
+            assert_eq!(services, vec![TypeKey::new::<ServiceB>(), TypeKey::new::<ServiceA>()]);
+        }
+        other => panic!("Expected CyclicDependency, got {:?}", other),
+    }
+    let err = assembler.assemble().unwrap_err().to_string();
+    assert!(err.contains("via services: assembly::ServiceB -> assembly::ServiceA"));
+}
+
+#[test]
+fn test_self_dependency_shows_arrow_chain() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    // Assemblies sharing a name are one vertex, so this loop passes the per-assembly check
+    assembler.register(Arc::new(MockServiceAssembly::new("Loop").provide(ServiceA))).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Loop").with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    assert!(matches!(&err, AssemblyError::CyclicDependency { .. }));
+    assert!(err.to_string().contains("Loop -> Loop"));
+}
+
+#[test]
+fn test_self_dependency_reported_by_name() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(MockServiceAssembly::new("Other").provide(ServiceB))).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Loop")
+            .provide(ServiceA)
+            .with_requires(vec![TypeKey::new::<ServiceB>(), TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    assert!(matches!(&err, AssemblyError::SelfDependency { assembly, type_key }
+        if assembly == "Loop" && *type_key == TypeKey::new::<ServiceA>()));
+    assert!(err.to_string().contains("'Loop' both provides and requires"));
+    assert!(assembler.initialization_order().is_empty());
+}
+
+#[test]
+fn test_self_dependency_covers_optional_and_collection_requirements() {
+    #[assembly(name = "OptionalLoop", provides = [ServiceA], requires_optional = [ServiceA])]
+    struct OptionalLoop {}
+    impl ServiceAssembly for OptionalLoop {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[assembly(
+        name = "CollectionLoop",
+        provides_collection = [ServiceB],
+        requires_collection = [ServiceB]
+    )]
+    struct CollectionLoop {}
+    impl ServiceAssembly for CollectionLoop {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    let assembler = Assembler::new(Arc::new(NoopMonitor), RuntimeMode::Debug);
+    assembler.register(Arc::new(OptionalLoop {})).unwrap();
+    assert!(matches!(assembler.assemble(), Err(AssemblyError::SelfDependency { assembly, type_key })
+        if assembly == "OptionalLoop" && type_key == TypeKey::new::<ServiceA>()));
+
+    let assembler = Assembler::new(Arc::new(NoopMonitor), RuntimeMode::Debug);
+    assembler.register(Arc::new(CollectionLoop {})).unwrap();
+    assert!(matches!(assembler.assemble(), Err(AssemblyError::SelfDependency { assembly, type_key })
+        if assembly == "CollectionLoop" && type_key == TypeKey::new::<ServiceB>()));
+}
+
+#[test]
+fn test_assembler_without_explicit_monitor() {
+    let assembler = Assembler::with_mode(RuntimeMode::Production);
+    assembler.register(Arc::new(DatabaseAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+    assert_eq!(assembler.initialization_order(), vec!["assembly::DatabaseAssembly"]);
+
+    let assembler = Assembler::new_default();
+    assembler.register(Arc::new(MockServiceAssembly::new("Default"))).unwrap();
+    assert!(assembler.assemble().is_ok());
+}
+
 #[test]
 fn test_complex_dependency_chain() {
     let monitor = Arc::new(NoopMonitor);
@@ -196,25 +366,25 @@ fn test_complex_dependency_chain() {
 
     // Create the chain: A <- B <- C
     let assembly_a = Arc::new(
-        MockServiceAssembly::new("LayerA").with_provides(vec![TypeKey::new::<ServiceA>()]),
+        MockServiceAssembly::new("LayerA").provide(ServiceA),
     );
 
     let assembly_b = Arc::new(
         MockServiceAssembly::new("LayerB")
-            .with_provides(vec![TypeKey::new::<ServiceB>()])
+            .provide(ServiceB)
             .with_requires(vec![TypeKey::new::<ServiceA>()]),
     );
 
     let assembly_c = Arc::new(
         MockServiceAssembly::new("LayerC")
-            .with_provides(vec![TypeKey::new::<ServiceC>()])
+            .provide(ServiceC)
             .with_requires(vec![TypeKey::new::<ServiceB>()]),
     );
 
     // Register in random order
-    assembler.register(assembly_b);
-    assembler.register(assembly_c);
-    assembler.register(assembly_a);
+    assembler.register(assembly_b).unwrap();
+    assembler.register(assembly_c).unwrap();
+    assembler.register(assembly_a).unwrap();
 
     assert!(assembler.assemble().is_ok());
 }
@@ -266,13 +436,58 @@ impl ServiceAssembly for LifecycleTrackingAssembly {
     }
 }
 
+#[test]
+fn test_on_ready_runs_after_start() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone()))).unwrap();
+
+    let ready = Arc::new(Mutex::new(false));
+    assembler
+        .assemble_then(|context| {
+            assert!(context.registry.contains::<ServiceA>());
+            assert_eq!(events.lock().unwrap().last().unwrap(), "start");
+            *ready.lock().unwrap() = true;
+        })
+        .unwrap();
+
+    assert!(*ready.lock().unwrap());
+}
+
+#[test]
+fn test_on_ready_skipped_when_start_fails() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(name = "FailingStart")]
+    struct FailingStart {}
+    impl ServiceAssembly for FailingStart {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+        fn start(&self, _context: &AssemblyContext) -> Result<()> {
+            Err(AssemblyError::GeneralError("port in use".to_string()))
+        }
+    }
+
+    assembler.register(Arc::new(FailingStart {})).unwrap();
+
+    let ready = Arc::new(Mutex::new(false));
+    let result = assembler.assemble_then(|_| *ready.lock().unwrap() = true);
+
+    assert!(result.is_err());
+    assert!(!*ready.lock().unwrap());
+}
+
 #[test]
 fn test_lifecycle_methods_called_in_order() {
     let events = Arc::new(Mutex::new(Vec::new()));
     let monitor = Arc::new(NoopMonitor);
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
-    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone())));
+    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone()))).unwrap();
     assembler.assemble().unwrap();
 
     let tracked = events.lock().unwrap();
@@ -285,7 +500,7 @@ fn test_shutdown_lifecycle() {
     let monitor = Arc::new(NoopMonitor);
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
-    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone())));
+    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone()))).unwrap();
     assembler.assemble().unwrap();
     assembler.shutdown().unwrap();
 
@@ -329,10 +544,10 @@ fn test_initialization_order_respects_dependencies() {
     // Register in reverse order
     assembler.register(Arc::new(SecondAssembly {
         events: events.clone(),
-    }));
+    })).unwrap();
     assembler.register(Arc::new(FirstAssembly {
         events: events.clone(),
-    }));
+    })).unwrap();
 
     assembler.assemble().unwrap();
 
@@ -360,7 +575,7 @@ fn test_init_failure_propagates() {
     let monitor = Arc::new(NoopMonitor);
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
-    assembler.register(Arc::new(FailingAssembly {}));
+    assembler.register(Arc::new(FailingAssembly {})).unwrap();
 
     let result = assembler.assemble();
     assert!(result.is_err());
@@ -391,7 +606,7 @@ impl ServiceAssembly for SimpleAssembly {
 #[test]
 fn test_macro_generates_name_from_struct() {
     let assembly = SimpleAssembly {};
-    assert_eq!(assembly.name(), "SimpleAssembly");
+    assert_eq!(assembly.name(), "assembly::SimpleAssembly");
 }
 
 #[test]
@@ -433,6 +648,12 @@ fn test_macro_multiple_provides() {
     assert_eq!(assembly.provides().len(), 3);
 }
 
+#[test]
+fn test_macro_provides_const() {
+    assert_eq!(MultiProvideAssembly::PROVIDES.len(), 3);
+    assert_eq!(MultiProvideAssembly::PROVIDES, &["ServiceA", "ServiceB", "ServiceC"]);
+}
+
 #[assembly(
     provides = [ServiceB],
     requires = [ServiceA]
@@ -448,7 +669,7 @@ impl ServiceAssembly for DependentAssembly {
 #[test]
 fn test_macro_provides_and_requires() {
     let assembly = DependentAssembly {};
-    assert_eq!(assembly.name(), "DependentAssembly");
+    assert_eq!(assembly.name(), "assembly::DependentAssembly");
     assert_eq!(assembly.provides().len(), 1);
     assert_eq!(assembly.requires().len(), 1);
 }
@@ -490,8 +711,8 @@ fn test_trait_object_dependencies() {
     let monitor = Arc::new(NoopMonitor);
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
-    assembler.register(Arc::new(CacheAssembly {}));
-    assembler.register(Arc::new(DatabaseAssembly {}));
+    assembler.register(Arc::new(CacheAssembly {})).unwrap();
+    assembler.register(Arc::new(DatabaseAssembly {})).unwrap();
 
     assert!(assembler.assemble().is_ok());
 }
@@ -501,17 +722,44 @@ fn test_trait_object_service_resolution() {
     let monitor = Arc::new(NoopMonitor);
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
-    assembler.register(Arc::new(DatabaseAssembly {}));
+    assembler.register(Arc::new(DatabaseAssembly {})).unwrap();
     assembler.assemble().unwrap();
 
     // Database service should be registered and resolvable
     // (This test verifies the assembly worked correctly)
 }
 
+#[test]
+fn test_bare_trait_object_keys_as_boxed() {
+    #[assembly(provides = [dyn Database], requires = [dyn Cache])]
+    struct BareTraitAssembly {}
+
+    let assembly = BareTraitAssembly {};
+    assert_eq!(assembly.provides(), DatabaseAssembly {}.provides());
+    assert_eq!(assembly.provides(), vec![TypeKey::new::<Box<dyn Database>>()]);
+    assert_eq!(assembly.requires(), vec![TypeKey::new::<Box<dyn Cache>>()]);
+}
+
 // ============================================================================
 // Runtime Mode Tests
 // ============================================================================
 
+#[test]
+fn test_runtime_mode_predicates() {
+    let cases = [
+        (RuntimeMode::Debug, false, false, true),
+        (RuntimeMode::Development, false, true, false),
+        (RuntimeMode::Production, true, false, false),
+        (RuntimeMode::Test, false, false, false),
+    ];
+
+    for (mode, production, development, debug) in cases {
+        assert_eq!(mode.is_production(), production, "{}", mode);
+        assert_eq!(mode.is_development(), development, "{}", mode);
+        assert_eq!(mode.is_debug(), debug, "{}", mode);
+    }
+}
+
 #[test]
 fn test_runtime_mode_debug() {
     let monitor = Arc::new(NoopMonitor);
@@ -553,7 +801,6 @@ fn test_runtime_mode_parse_case_insensitive() {
 #[test]
 fn test_runtime_mode_parse_invalid() {
     assert!(RuntimeMode::parse("invalid").is_err());
-    assert!(RuntimeMode::parse("test").is_err());
     assert!(RuntimeMode::parse("").is_err());
 
     let err = RuntimeMode::parse("invalid").unwrap_err();
@@ -567,11 +814,93 @@ fn test_runtime_mode_display() {
     assert_eq!(format!("{}", RuntimeMode::Production), "production");
 }
 
+#[test]
+fn test_runtime_mode_parse_test_variant() {
+    assert_eq!(RuntimeMode::parse("test").unwrap(), RuntimeMode::Test);
+    assert_eq!(RuntimeMode::parse("TEST").unwrap(), RuntimeMode::Test);
+}
+
+#[test]
+fn test_runtime_mode_display_test_variant() {
+    assert_eq!(format!("{}", RuntimeMode::Test), "test");
+    assert_eq!(
+        RuntimeMode::parse(&RuntimeMode::Test.to_string()).unwrap(),
+        RuntimeMode::Test
+    );
+}
+
+/// Serializes tests that modify process environment variables
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn test_runtime_mode_from_env() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    unsafe { std::env::set_var("ASSEMBLR_TEST_MODE", "prod") };
+
+    assert_eq!(
+        RuntimeMode::from_env("ASSEMBLR_TEST_MODE").unwrap(),
+        RuntimeMode::Production
+    );
+    assert_eq!(
+        RuntimeMode::from_env_or("ASSEMBLR_TEST_MODE", RuntimeMode::Debug).unwrap(),
+        RuntimeMode::Production
+    );
+
+    unsafe { std::env::remove_var("ASSEMBLR_TEST_MODE") };
+}
+
+#[test]
+fn test_runtime_mode_from_env_unset() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    unsafe { std::env::remove_var("ASSEMBLR_TEST_MODE") };
+
+    let err = RuntimeMode::from_env("ASSEMBLR_TEST_MODE").unwrap_err();
+    assert!(matches!(err, AssemblyError::InvalidRuntimeMode(_)));
+    assert!(err.to_string().contains("ASSEMBLR_TEST_MODE"));
+
+    assert_eq!(
+        RuntimeMode::from_env_or("ASSEMBLR_TEST_MODE", RuntimeMode::Development).unwrap(),
+        RuntimeMode::Development
+    );
+}
+
+#[test]
+fn test_runtime_mode_from_env_invalid() {
+    let _guard = ENV_LOCK.lock().unwrap();
+    unsafe { std::env::set_var("ASSEMBLR_TEST_MODE", "staging") };
+
+    assert!(RuntimeMode::from_env("ASSEMBLR_TEST_MODE").is_err());
+    assert!(RuntimeMode::from_env_or("ASSEMBLR_TEST_MODE", RuntimeMode::Debug).is_err());
+
+    unsafe { std::env::remove_var("ASSEMBLR_TEST_MODE") };
+}
+
+#[cfg(unix)]
+#[test]
+fn test_runtime_mode_from_env_not_unicode() {
+    use std::os::unix::ffi::OsStringExt;
+
+    let _guard = ENV_LOCK.lock().unwrap();
+    let value = std::ffi::OsString::from_vec(vec![0xff]);
+    unsafe { std::env::set_var("ASSEMBLR_TEST_MODE", value) };
+
+    let err = RuntimeMode::from_env("ASSEMBLR_TEST_MODE").unwrap_err();
+    assert!(matches!(err, AssemblyError::InvalidRuntimeMode(_)));
+    assert!(err.to_string().contains("not valid unicode"));
+    assert!(matches!(
+        RuntimeMode::from_env_or("ASSEMBLR_TEST_MODE", RuntimeMode::Debug),
+        Err(AssemblyError::InvalidRuntimeMode(_))
+    ));
+
+    unsafe { std::env::remove_var("ASSEMBLR_TEST_MODE") };
+}
+
 #[test]
 fn test_runtime_mode_is_valid() {
     assert!(RuntimeMode::Debug.is_valid());
     assert!(RuntimeMode::Development.is_valid());
     assert!(RuntimeMode::Production.is_valid());
+    assert!(RuntimeMode::Test.is_valid());
 }
 
 // ============================================================================
@@ -614,6 +943,82 @@ fn test_typekey_hash() {
     assert_eq!(map.get(&key_b), Some(&"ServiceB"));
 }
 
+#[test]
+fn test_typekey_accessors() {
+    let key = TypeKey::new::<ServiceA>();
+    assert!(key.type_name().contains("ServiceA"));
+    assert_eq!(key.type_id(), std::any::TypeId::of::<ServiceA>());
+}
+
+#[test]
+fn test_typekey_ordering_by_name() {
+    let mut keys = vec![
+        TypeKey::new::<ServiceC>(),
+        TypeKey::new::<ServiceA>(),
+        TypeKey::new::<ServiceB>(),
+    ];
+    keys.sort();
+
+    assert_eq!(
+        keys,
+        vec![
+            TypeKey::new::<ServiceA>(),
+            TypeKey::new::<ServiceB>(),
+            TypeKey::new::<ServiceC>(),
+        ]
+    );
+}
+
+#[test]
+fn test_typekey_from_name_is_deterministic() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of(key: &TypeKey) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let first = TypeKey::from_name("db.primary");
+    let second = TypeKey::from_name("db.primary");
+
+    assert_eq!(first, second);
+    assert_eq!(hash_of(&first), hash_of(&second));
+    assert_ne!(first, TypeKey::from_name("db.replica"));
+    assert!(first.is_dynamic());
+    assert!(!TypeKey::new::<ServiceA>().is_dynamic());
+    assert_eq!(first.to_string(), "db.primary");
+}
+
+#[test]
+fn test_typekey_from_parts() {
+    let key = TypeKey::from_parts(std::any::TypeId::of::<ServiceA>(), "ServiceA".to_string());
+
+    assert_eq!(key.type_id(), std::any::TypeId::of::<ServiceA>());
+    assert_eq!(key.type_name(), "ServiceA");
+}
+
+#[test]
+fn test_dynamic_keys_order_assemblies() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    let consumer = MockServiceAssembly::new("PluginConsumer")
+        .with_requires(vec![TypeKey::from_name("db.primary")]);
+    let mut provider = MockServiceAssembly::new("PluginProvider");
+    provider.provides.push(TypeKey::from_name("db.primary"));
+
+    assembler.register(Arc::new(consumer)).unwrap();
+    assembler.register(Arc::new(provider)).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(
+        assembler.initialization_order(),
+        vec!["PluginProvider", "PluginConsumer"]
+    );
+}
+
 // ============================================================================
 // AssemblyError Tests
 // ============================================================================
@@ -638,7 +1043,10 @@ fn test_error_missing_dependency_display() {
 
 #[test]
 fn test_error_cyclic_dependency_display() {
-    let err = AssemblyError::CyclicDependency("A -> B -> A".to_string());
+    let err = AssemblyError::CyclicDependency {
+        cycle: "A -> B -> A".to_string(),
+        services: Vec::new(),
+    };
     let msg = err.to_string();
     assert!(msg.contains("Cyclic dependency"));
     assert!(msg.contains("A -> B -> A"));
@@ -650,6 +1058,29 @@ fn test_error_general_error_display() {
     assert_eq!(err.to_string(), "Something went wrong");
 }
 
+#[test]
+fn test_error_is_transient() {
+    let connection = AssemblyError::GeneralError("connection refused".to_string());
+    let transient = AssemblyError::Transient(Box::new(connection));
+    assert!(transient.is_transient());
+    assert_eq!(transient.to_string(), "connection refused");
+
+    let failure = AssemblyError::PhaseFailure {
+        assembly: "Database".to_string(),
+        phase: Phase::Init,
+        source: Box::new(transient),
+    };
+    assert!(failure.is_transient());
+
+    let cycle = AssemblyError::CyclicDependency {
+        cycle: "A -> B -> A".to_string(),
+        services: Vec::new(),
+    };
+    assert!(!cycle.is_transient());
+    assert!(!AssemblyError::Transient(Box::new(cycle)).is_transient());
+    assert!(!AssemblyError::GeneralError("fatal".to_string()).is_transient());
+}
+
 // ============================================================================
 // LogMonitor Tests
 // ============================================================================
@@ -688,6 +1119,121 @@ impl LogMonitor for MockLogMonitor {
     }
 }
 
+#[test]
+fn test_capturing_monitor_records_assembly_messages() {
+    let monitor = Arc::new(CapturingMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Debug);
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    assert!(monitor.messages().contains(&(
+        Level::Debug,
+        "Initialized: assembly::SimpleAssembly (assembly=assembly::SimpleAssembly, phase=init)"
+            .to_string()
+    )));
+    assert!(monitor.messages_at_level(Level::Error).is_empty());
+}
+
+#[test]
+fn test_capturing_monitor_filters_by_level() {
+    let monitor = CapturingMonitor::new();
+    monitor.info("first");
+    monitor.warn("careful");
+    monitor.info("second");
+
+    assert_eq!(monitor.messages().len(), 3);
+    assert_eq!(monitor.messages_at_level(Level::Info), vec!["first", "second"]);
+    assert_eq!(monitor.messages_at_level(Level::Warn), vec!["careful"]);
+}
+
+#[test]
+fn test_filtering_monitor_drops_messages_below_threshold() {
+    let capturing = Arc::new(CapturingMonitor::new());
+    let monitor = FilteringMonitor::new(capturing.clone(), Level::Warn);
+
+    monitor.debug("dropped");
+    monitor.info("dropped");
+    monitor.warn("kept warning");
+    monitor.error("kept error");
+
+    assert_eq!(
+        capturing.messages(),
+        vec![
+            (Level::Warn, "kept warning".to_string()),
+            (Level::Error, "kept error".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_level_from_runtime_mode() {
+    assert_eq!(Level::from_runtime_mode(RuntimeMode::Debug), Level::Debug);
+    assert_eq!(Level::from_runtime_mode(RuntimeMode::Development), Level::Info);
+    assert_eq!(Level::from_runtime_mode(RuntimeMode::Production), Level::Warn);
+}
+
+type RecordedFields = Vec<(String, String)>;
+
+#[derive(Default)]
+struct FieldRecordingMonitor {
+    events: Mutex<Vec<(Level, RecordedFields)>>,
+}
+
+impl LogMonitor for FieldRecordingMonitor {
+    fn debug(&self, _: &str) {}
+    fn info(&self, _: &str) {}
+    fn warn(&self, _: &str) {}
+    fn error(&self, _: &str) {}
+
+    fn event(&self, level: Level, _message: &str, fields: &[(&str, &str)]) {
+        let fields = fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        self.events.lock().unwrap().push((level, fields));
+    }
+}
+
+#[test]
+fn test_lifecycle_transitions_log_structured_fields() {
+    let monitor = Arc::new(FieldRecordingMonitor::default());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Debug);
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let events = monitor.events.lock().unwrap();
+    let phases: Vec<&str> = events
+        .iter()
+        .map(|(level, fields)| {
+            assert_eq!(*level, Level::Debug);
+            assert_eq!(
+                fields[0],
+                ("assembly".to_string(), "assembly::SimpleAssembly".to_string())
+            );
+            assert_eq!(fields[1].0, "phase");
+            fields[1].1.as_str()
+        })
+        .collect();
+    assert_eq!(phases, vec!["configure", "init", "prepare", "start"]);
+}
+
+#[test]
+fn test_event_default_formats_fields() {
+    let monitor = CapturingMonitor::new();
+    monitor.event(Level::Warn, "Slow", &[("assembly", "db"), ("phase", "start")]);
+    monitor.event(Level::Info, "Plain", &[]);
+
+    assert_eq!(
+        monitor.messages(),
+        vec![
+            (Level::Warn, "Slow (assembly=db, phase=start)".to_string()),
+            (Level::Info, "Plain".to_string()),
+        ]
+    );
+}
+
 #[test]
 fn test_custom_log_monitor() {
     let monitor = Arc::new(MockLogMonitor::new());
@@ -728,7 +1274,7 @@ fn test_monitor_messages_during_assembly() {
         }
     }
 
-    assembler.register(Arc::new(TestAssembly {}));
+    assembler.register(Arc::new(TestAssembly {})).unwrap();
     assembler.assemble().unwrap();
 
     let messages = monitor.get_messages();
@@ -761,53 +1307,383 @@ fn test_mutable_context_registry_access() {
         }
     }
 
-    assembler.register(Arc::new(ContextTestAssembly {}));
+    assembler.register(Arc::new(ContextTestAssembly {})).unwrap();
     assert!(assembler.assemble().is_ok());
 }
 
 #[test]
-fn test_assembly_context_cloning() {
+fn test_resolve_required_reports_missing_service() {
     let monitor = Arc::new(NoopMonitor);
-    let registry = Arc::new(ServiceRegistry::new());
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
-    let context = AssemblyContext {
-        registry: registry.clone(),
-        log_monitor: monitor.clone(),
-        mode: RuntimeMode::Debug,
-    };
+    #[assembly(name = "Preparer", provides = [ServiceA])]
+    struct PreparerAssembly {}
+    impl ServiceAssembly for PreparerAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+        fn prepare(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.resolve_required::<ServiceA>()?;
+            context.resolve_required::<ServiceC>()?;
+            Ok(())
+        }
+    }
 
-    let cloned = context.clone();
-    assert_eq!(cloned.mode, RuntimeMode::Debug);
+    assembler.register(Arc::new(PreparerAssembly {})).unwrap();
+
+    match assembler.assemble() {
+        Err(AssemblyError::PhaseFailure { phase, source, .. }) => {
+            assert_eq!(phase, Phase::Prepare);
+            match *source {
+                AssemblyError::MissingDependency { assembly, message } => {
+                    assert_eq!(assembly, "Preparer");
+                    assert!(message.contains("ServiceC"));
+                }
+                other => panic!("Expected MissingDependency, got {:?}", other),
+            }
+        }
+        other => panic!("Expected PhaseFailure, got {:?}", other),
+    }
 }
 
 #[test]
-fn test_context_mode_propagation() {
+fn test_resolve_required_explains_out_of_order_init() {
     let monitor = Arc::new(NoopMonitor);
-    let assembler = Assembler::new(monitor, RuntimeMode::Production);
-
-    let captured_mode = Arc::new(Mutex::new(None));
-    let captured_mode_clone = captured_mode.clone();
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
-    #[assembly(provides = [ServiceA])]
-    struct ModeTestAssembly {
-        captured: Arc<Mutex<Option<RuntimeMode>>>,
-    }
-    impl ServiceAssembly for ModeTestAssembly {
+    // Cache resolves ServiceA without declaring it, while Store depends on Cache
+    #[assembly(name = "Cache", provides = [ServiceB])]
+    struct CacheAssembly {}
+    impl ServiceAssembly for CacheAssembly {
         fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
-            *self.captured.lock().unwrap() = Some(context.mode);
-            context.registry.register(Arc::new(ServiceA));
+            context.resolve_required::<ServiceA>()?;
+            context.registry.register(Arc::new(ServiceB));
             Ok(())
         }
     }
 
-    assembler.register(Arc::new(ModeTestAssembly {
+    assembler.register(Arc::new(CacheAssembly {})).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Store")
+            .provide(ServiceA)
+            .with_requires(vec![TypeKey::new::<ServiceB>()]),
+    )).unwrap();
+
+    match assembler.assemble() {
+        Err(AssemblyError::PhaseFailure { source, .. }) => match *source {
+            AssemblyError::MissingDependency { assembly, message } => {
+                assert_eq!(assembly, "Cache");
+                assert!(message.contains("is provided by 'Store', which has not been initialized yet"));
+                assert!(message.contains("add it to the requires of 'Cache'"));
+            }
+            other => panic!("Expected MissingDependency, got {:?}", other),
+        },
+        other => panic!("Expected PhaseFailure, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_ordering_hint_names_the_provider_in_use() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Development);
+
+    #[assembly(name = "Cache", provides = [ServiceB])]
+    struct CacheAssembly {}
+    impl ServiceAssembly for CacheAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.resolve_required::<ServiceA>()?;
+            context.registry.register(Arc::new(ServiceB));
+            Ok(())
+        }
+    }
+
+    // Both stores provide ServiceA; the last registered one is used
+    assembler.register(Arc::new(CacheAssembly {})).unwrap();
+    for name in ["Store2", "Store1"] {
+        assembler.register(Arc::new(
+            MockServiceAssembly::new(name)
+                .provide(ServiceA)
+                .with_requires(vec![TypeKey::new::<ServiceB>()]),
+        )).unwrap();
+    }
+
+    match assembler.assemble() {
+        Err(AssemblyError::PhaseFailure { source, .. }) => match *source {
+            AssemblyError::MissingDependency { message, .. } => {
+                assert!(message.contains("is provided by 'Store1'"), "Message was: {}", message);
+            }
+            other => panic!("Expected MissingDependency, got {:?}", other),
+        },
+        other => panic!("Expected PhaseFailure, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_resolve_required_declared_dependency_has_no_ordering_hint() {
+    let monitor = Arc::new(NoopMonitor);
+    // Production skips the provision check, so the consumer sees the missing service itself
+    let assembler = Assembler::new(monitor, RuntimeMode::Production);
+
+    #[assembly(name = "Consumer", requires = [ServiceA])]
+    struct DeclaringConsumer {}
+    impl ServiceAssembly for DeclaringConsumer {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.resolve_required::<ServiceA>()?;
+            Ok(())
+        }
+    }
+
+    // Declares ServiceA but never registers it
+    #[assembly(name = "Provider", provides = [ServiceA])]
+    struct ForgetfulProvider {}
+    impl ServiceAssembly for ForgetfulProvider {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(ForgetfulProvider {})).unwrap();
+    assembler.register(Arc::new(DeclaringConsumer {})).unwrap();
+
+    match assembler.assemble() {
+        Err(AssemblyError::PhaseFailure { source, .. }) => match *source {
+            AssemblyError::MissingDependency { assembly, message } => {
+                assert_eq!(assembly, "Consumer");
+                assert!(message.contains("not found in registry"), "Message was: {}", message);
+                assert!(!message.contains("has not been initialized yet"), "Message was: {}", message);
+            }
+            other => panic!("Expected MissingDependency, got {:?}", other),
+        },
+        other => panic!("Expected PhaseFailure, got {:?}", other),
+    }
+}
+
+#[assembly(name = "Provider", provides = [ServiceA, ServiceB])]
+struct AuditedProvider {}
+impl ServiceAssembly for AuditedProvider {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        context.registry.register(Arc::new(ServiceA));
+        context.registry.register(Arc::new(ServiceB));
+        Ok(())
+    }
+}
+
+#[assembly(name = "Consumer", requires = [ServiceA])]
+struct AuditedConsumer {}
+impl ServiceAssembly for AuditedConsumer {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        context.require::<ServiceA>()?;
+        context.require::<ServiceB>()?;
+        Ok(())
+    }
+    fn start(&self, context: &AssemblyContext) -> Result<()> {
+        context.require::<ServiceB>()?;
+        Ok(())
+    }
+}
+
+fn audited_assembler(mode: RuntimeMode) -> Assembler {
+    let assembler = Assembler::with_mode(mode);
+    assembler.register(Arc::new(AuditedProvider {})).unwrap();
+    assembler.register(Arc::new(AuditedConsumer {})).unwrap();
+    assembler.assemble().unwrap();
+    assembler
+}
+
+#[test]
+fn test_undeclared_dependencies_flags_hidden_coupling() {
+    let assembler = audited_assembler(RuntimeMode::Development);
+
+    assert_eq!(
+        assembler.undeclared_dependencies(),
+        vec![("Consumer".to_string(), TypeKey::new::<ServiceB>())]
+    );
+}
+
+#[test]
+fn test_undeclared_dependencies_not_recorded_in_production() {
+    let assembler = audited_assembler(RuntimeMode::Production);
+
+    assert!(assembler.undeclared_dependencies().is_empty());
+}
+
+#[test]
+fn test_undeclared_dependencies_not_recorded_in_test_mode() {
+    let assembler = audited_assembler(RuntimeMode::Test);
+
+    assert!(assembler.undeclared_dependencies().is_empty());
+}
+
+struct ConfigService {
+    port: u16,
+}
+
+#[assembly(name = "Settings", provides = [ConfigService], requires = [ServiceA])]
+struct SettingsAssembly {}
+impl ServiceAssembly for SettingsAssembly {
+    fn configure(&self, context: &MutableAssemblyContext) -> Result<()> {
+        context.registry.register(Arc::new(ConfigService { port: 8080 }));
+        Ok(())
+    }
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[assembly(name = "Server", provides = [ServiceA])]
+struct ServerAssembly {
+    port: Mutex<Option<u16>>,
+}
+impl ServiceAssembly for ServerAssembly {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        let config = context.resolve_required::<ConfigService>()?;
+        *self.port.lock().unwrap() = Some(config.port);
+        context.registry.register(Arc::new(ServiceA));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_configure_runs_before_any_init() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    let server = Arc::new(ServerAssembly { port: Mutex::new(None) });
+
+    // Settings requires Server, so Server is initialized first yet still sees the configuration
+    assembler.register(Arc::new(SettingsAssembly {})).unwrap();
+    assembler.register(server.clone()).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(assembler.initialization_order(), vec!["Server", "Settings"]);
+    assert_eq!(*server.port.lock().unwrap(), Some(8080));
+}
+
+#[test]
+fn test_contexts_name_current_assembly() {
+    let monitor = Arc::new(CapturingMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Debug);
+
+    #[assembly(name = "SelfAware")]
+    struct SelfAwareAssembly {}
+    impl ServiceAssembly for SelfAwareAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context
+                .log_monitor
+                .info(&format!("init of {}", context.current_assembly));
+            Ok(())
+        }
+        fn start(&self, context: &AssemblyContext) -> Result<()> {
+            context
+                .log_monitor
+                .info(&format!("start of {}", context.current_assembly));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(SelfAwareAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(
+        monitor.messages_at_level(Level::Info),
+        vec!["[SelfAware] init of SelfAware", "[SelfAware] start of SelfAware"]
+    );
+}
+
+#[test]
+fn test_assembly_context_cloning() {
+    let monitor = Arc::new(NoopMonitor);
+    let registry = Arc::new(ServiceRegistry::new());
+
+    let context = AssemblyContext {
+        registry: registry.clone(),
+        log_monitor: monitor.clone(),
+        mode: RuntimeMode::Debug,
+        current_assembly: "Cloned".to_string(),
+        resolution_audit: None,
+    };
+
+    let cloned = context.clone();
+    assert_eq!(cloned.mode, RuntimeMode::Debug);
+    assert_eq!(cloned.current_assembly, "Cloned");
+}
+
+#[test]
+fn test_context_mode_propagation() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Production);
+
+    let captured_mode = Arc::new(Mutex::new(None));
+    let captured_mode_clone = captured_mode.clone();
+
+    #[assembly(provides = [ServiceA])]
+    struct ModeTestAssembly {
+        captured: Arc<Mutex<Option<RuntimeMode>>>,
+    }
+    impl ServiceAssembly for ModeTestAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            *self.captured.lock().unwrap() = Some(context.mode);
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(ModeTestAssembly {
         captured: captured_mode_clone,
-    }));
+    })).unwrap();
     assembler.assemble().unwrap();
 
     assert_eq!(captured_mode.lock().unwrap().unwrap(), RuntimeMode::Production);
 }
 
+#[test]
+fn test_set_monitor_before_assemble() {
+    let assembler = Assembler::with_mode(RuntimeMode::Debug);
+    let monitor = Arc::new(CapturingMonitor::new());
+    assembler.set_monitor(monitor.clone());
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let messages = monitor.messages_at_level(Level::Debug);
+    for transition in ["Initialized", "Prepared", "Started"] {
+        let expected = format!("{}: assembly::SimpleAssembly", transition);
+        assert!(messages.iter().any(|m| m.contains(&expected)), "missing {}", expected);
+    }
+}
+
+#[test]
+fn test_assembler_builder() {
+    let monitor = Arc::new(CapturingMonitor::new());
+    let observer = Arc::new(RecordingObserver::new());
+    let assembler = Assembler::builder()
+        .monitor(monitor.clone())
+        .mode(RuntimeMode::Production)
+        .observer(observer.clone())
+        .phase_timeout(std::time::Duration::from_secs(5))
+        .parallel(true)
+        .build();
+
+    #[assembly(name = "Built")]
+    struct BuiltAssembly {
+        captured: Mutex<Option<RuntimeMode>>,
+    }
+    impl ServiceAssembly for BuiltAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            *self.captured.lock().unwrap() = Some(context.mode);
+            Ok(())
+        }
+    }
+
+    let built = Arc::new(BuiltAssembly { captured: Mutex::new(None) });
+    assembler.register(built.clone()).unwrap();
+    assert!(assembler.assemble().is_ok());
+
+    assert_eq!(*built.captured.lock().unwrap(), Some(RuntimeMode::Production));
+    assert!(monitor.messages_at_level(Level::Debug).iter().any(|m| m.contains("Initialized: Built")));
+    assert!(observer.events().contains(&"end:Built:start:ok".to_string()));
+}
+
 #[test]
 fn test_context_log_monitor_access() {
     let monitor = Arc::new(MockLogMonitor::new());
@@ -823,7 +1699,7 @@ fn test_context_log_monitor_access() {
         }
     }
 
-    assembler.register(Arc::new(LogTestAssembly {}));
+    assembler.register(Arc::new(LogTestAssembly {})).unwrap();
     assembler.assemble().unwrap();
 
     let messages = monitor.get_messages();
@@ -873,8 +1749,8 @@ fn test_shutdown_reverse_order() {
         }
     }
 
-    assembler.register(Arc::new(FirstShutdownAssembly { events: events.clone() }));
-    assembler.register(Arc::new(SecondShutdownAssembly { events: events.clone() }));
+    assembler.register(Arc::new(FirstShutdownAssembly { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(SecondShutdownAssembly { events: events.clone() })).unwrap();
     assembler.assemble().unwrap();
     assembler.shutdown().unwrap();
 
@@ -914,8 +1790,8 @@ fn test_shutdown_with_finalize_error() {
         }
     }
 
-    assembler.register(Arc::new(FailingFinalizeAssembly {}));
-    assembler.register(Arc::new(SuccessfulAssembly {}));
+    assembler.register(Arc::new(FailingFinalizeAssembly {})).unwrap();
+    assembler.register(Arc::new(SuccessfulAssembly {})).unwrap();
     assembler.assemble().unwrap();
 
     let result = assembler.shutdown();
@@ -940,7 +1816,7 @@ fn test_shutdown_with_shutdown_error() {
         }
     }
 
-    assembler.register(Arc::new(FailingShutdownAssembly {}));
+    assembler.register(Arc::new(FailingShutdownAssembly {})).unwrap();
     assembler.assemble().unwrap();
 
     let result = assembler.shutdown();
@@ -968,7 +1844,7 @@ fn test_shutdown_multiple_errors() {
         }
     }
 
-    assembler.register(Arc::new(FailingBothAssembly {}));
+    assembler.register(Arc::new(FailingBothAssembly {})).unwrap();
     assembler.assemble().unwrap();
 
     let result = assembler.shutdown();
@@ -993,13 +1869,31 @@ fn test_shutdown_without_assemble() {
         }
     }
 
-    assembler.register(Arc::new(SimpleShutdownAssembly {}));
+    assembler.register(Arc::new(SimpleShutdownAssembly {})).unwrap();
     // Don't call assemble()
 
     // Shutdown should still work (no-op effectively)
     assert!(assembler.shutdown().is_ok());
 }
 
+#[test]
+fn test_shutdown_twice_runs_hooks_once() {
+    let observer = Arc::new(RecordingObserver::new());
+    let monitor = Arc::new(NoopMonitor);
+    let assembler =
+        Assembler::new(monitor, RuntimeMode::Debug).with_observer(observer.clone());
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+    assert!(assembler.shutdown().is_ok());
+    assert!(assembler.shutdown().is_ok());
+
+    let events = observer.events();
+    let count = |event: &str| events.iter().filter(|e| *e == event).count();
+    assert_eq!(count("start:assembly::SimpleAssembly:finalize"), 1);
+    assert_eq!(count("start:assembly::SimpleAssembly:shutdown"), 1);
+}
+
 // ============================================================================
 // Lifecycle Phase Tests
 // ============================================================================
@@ -1021,7 +1915,7 @@ fn test_prepare_phase_failure() {
         }
     }
 
-    assembler.register(Arc::new(FailingPrepareAssembly {}));
+    assembler.register(Arc::new(FailingPrepareAssembly {})).unwrap();
     let result = assembler.assemble();
     assert!(result.is_err());
 }
@@ -1043,7 +1937,7 @@ fn test_start_phase_failure() {
         }
     }
 
-    assembler.register(Arc::new(FailingStartAssembly {}));
+    assembler.register(Arc::new(FailingStartAssembly {})).unwrap();
     let result = assembler.assemble();
     assert!(result.is_err());
 }
@@ -1065,7 +1959,7 @@ fn test_finalize_phase_failure() {
         }
     }
 
-    assembler.register(Arc::new(FailingFinalizeOnly {}));
+    assembler.register(Arc::new(FailingFinalizeOnly {})).unwrap();
     assembler.assemble().unwrap();
 
     let result = assembler.shutdown();
@@ -1097,7 +1991,7 @@ fn test_lifecycle_phases_access_registry() {
         }
     }
 
-    assembler.register(Arc::new(PhaseRegistryAssembly {}));
+    assembler.register(Arc::new(PhaseRegistryAssembly {})).unwrap();
     assert!(assembler.assemble().is_ok());
 }
 
@@ -1113,9 +2007,9 @@ fn test_register_multiple_assemblies() {
     for i in 0..10 {
         let mock = Arc::new(
             MockServiceAssembly::new(&format!("Assembly{}", i))
-                .with_provides(vec![TypeKey::new::<ServiceA>()])
+                .provide(ServiceA)
         );
-        assembler.register(mock);
+        assembler.register(mock).unwrap();
     }
 
     // Last registered should be used
@@ -1139,7 +2033,7 @@ fn test_empty_assembly() {
     assert_eq!(assembly.provides().len(), 0);
     assert_eq!(assembly.requires().len(), 0);
 
-    assembler.register(Arc::new(EmptyAssembly {}));
+    assembler.register(Arc::new(EmptyAssembly {})).unwrap();
     assert!(assembler.assemble().is_ok());
 }
 
@@ -1166,13 +2060,102 @@ fn test_assembly_providing_same_service() {
         }
     }
 
-    assembler.register(Arc::new(FirstProvider {}));
-    assembler.register(Arc::new(SecondProvider {}));
+    assembler.register(Arc::new(FirstProvider {})).unwrap();
+    assembler.register(Arc::new(SecondProvider {})).unwrap();
 
     // Last registered wins
     assert!(assembler.assemble().is_ok());
 }
 
+#[test]
+fn test_assemblies_with_tag() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(name = "Api", tags = ["web"])]
+    struct ApiTagged {}
+    impl ServiceAssembly for ApiTagged {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[assembly(name = "Assets", tags = ["web", "static"])]
+    struct AssetsTagged {}
+    impl ServiceAssembly for AssetsTagged {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[assembly(name = "Storage", tags = ["db"])]
+    struct StorageTagged {}
+    impl ServiceAssembly for StorageTagged {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    assert_eq!(AssetsTagged {}.tags(), vec!["web", "static"]);
+    assert!(MockServiceAssembly::new("Untagged").tags().is_empty());
+
+    assembler.register(Arc::new(ApiTagged {})).unwrap();
+    assembler.register(Arc::new(StorageTagged {})).unwrap();
+    assembler.register(Arc::new(AssetsTagged {})).unwrap();
+
+    assert_eq!(assembler.assemblies_with_tag("web"), vec!["Api", "Assets"]);
+    assert_eq!(assembler.assemblies_with_tag("db"), vec!["Storage"]);
+    assert!(assembler.assemblies_with_tag("background").is_empty());
+}
+
+#[test]
+fn test_registered_names_and_count() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    assert!(assembler.registered_names().is_empty());
+    assert_eq!(assembler.registered_count(), 0);
+
+    assembler.register(Arc::new(MockServiceAssembly::new("Database"))).unwrap();
+    assembler.register(Arc::new(MockServiceAssembly::new("Cache"))).unwrap();
+    assembler.register(Arc::new(MockServiceAssembly::new("Web"))).unwrap();
+
+    assert_eq!(assembler.registered_names(), vec!["Database", "Cache", "Web"]);
+    assert_eq!(assembler.registered_count(), 3);
+}
+
+#[test]
+fn test_register_after_assemble_is_rejected() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let result = assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone())));
+    let err = result.unwrap_err();
+    assert!(err.to_string().contains("after assemble"), "Error was: {}", err);
+
+    let batch_result = assembler.register_all(vec![Arc::new(HealthyAssembly {})]);
+    assert!(batch_result.is_err());
+
+    assert_eq!(assembler.initialization_order(), vec!["assembly::SimpleAssembly"]);
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_register_rejected_after_restart() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+    assembler.restart().unwrap();
+
+    // Restart re-assembles, so the assembler is still closed for registration
+    assert!(assembler.register(Arc::new(HealthyAssembly {})).is_err());
+}
+
 // ============================================================================
 // Complex Dependency Scenarios
 // ============================================================================
@@ -1186,34 +2169,34 @@ fn test_diamond_dependency_pattern() {
 
     // A provides base
     let assembly_a = Arc::new(
-        MockServiceAssembly::new("A").with_provides(vec![TypeKey::new::<ServiceA>()])
+        MockServiceAssembly::new("A").provide(ServiceA)
     );
 
     // B requires A
     let assembly_b = Arc::new(
         MockServiceAssembly::new("B")
-            .with_provides(vec![TypeKey::new::<ServiceB>()])
+            .provide(ServiceB)
             .with_requires(vec![TypeKey::new::<ServiceA>()])
     );
 
     // C requires A
     let assembly_c = Arc::new(
         MockServiceAssembly::new("C")
-            .with_provides(vec![TypeKey::new::<ServiceC>()])
+            .provide(ServiceC)
             .with_requires(vec![TypeKey::new::<ServiceA>()])
     );
 
     // D requires B and C
     let assembly_d = Arc::new(
         MockServiceAssembly::new("D")
-            .with_provides(vec![TypeKey::new::<ServiceD>()])
+            .provide(ServiceD)
             .with_requires(vec![TypeKey::new::<ServiceB>(), TypeKey::new::<ServiceC>()])
     );
 
-    assembler.register(assembly_d);
-    assembler.register(assembly_c);
-    assembler.register(assembly_b);
-    assembler.register(assembly_a);
+    assembler.register(assembly_d).unwrap();
+    assembler.register(assembly_c).unwrap();
+    assembler.register(assembly_b).unwrap();
+    assembler.register(assembly_a).unwrap();
 
     assert!(assembler.assemble().is_ok());
 }
@@ -1230,26 +2213,26 @@ fn test_multiple_paths_to_dependency() {
 
     // A is the common dependency
     let assembly_a = Arc::new(
-        MockServiceAssembly::new("A").with_provides(vec![TypeKey::new::<ServiceA>()])
+        MockServiceAssembly::new("A").provide(ServiceA)
     );
 
     // B requires A
     let assembly_b = Arc::new(
         MockServiceAssembly::new("B")
-            .with_provides(vec![TypeKey::new::<ServiceB>()])
+            .provide(ServiceB)
             .with_requires(vec![TypeKey::new::<ServiceA>()])
     );
 
     // C requires A and B (two paths to A)
     let assembly_c = Arc::new(
         MockServiceAssembly::new("C")
-            .with_provides(vec![TypeKey::new::<ServiceC>()])
+            .provide(ServiceC)
             .with_requires(vec![TypeKey::new::<ServiceA>(), TypeKey::new::<ServiceB>()])
     );
 
-    assembler.register(assembly_c);
-    assembler.register(assembly_b);
-    assembler.register(assembly_a);
+    assembler.register(assembly_c).unwrap();
+    assembler.register(assembly_b).unwrap();
+    assembler.register(assembly_a).unwrap();
 
     assert!(assembler.assemble().is_ok());
 }
@@ -1261,72 +2244,110 @@ fn test_independent_assembly_groups() {
 
     // Group 1: A -> B
     let assembly_a = Arc::new(
-        MockServiceAssembly::new("A").with_provides(vec![TypeKey::new::<ServiceA>()])
+        MockServiceAssembly::new("A").provide(ServiceA)
     );
     let assembly_b = Arc::new(
         MockServiceAssembly::new("B")
-            .with_provides(vec![TypeKey::new::<ServiceB>()])
+            .provide(ServiceB)
             .with_requires(vec![TypeKey::new::<ServiceA>()])
     );
 
     // Group 2: C (independent)
     let assembly_c = Arc::new(
-        MockServiceAssembly::new("C").with_provides(vec![TypeKey::new::<ServiceC>()])
+        MockServiceAssembly::new("C").provide(ServiceC)
     );
 
-    assembler.register(assembly_b);
-    assembler.register(assembly_c);
-    assembler.register(assembly_a);
+    assembler.register(assembly_b).unwrap();
+    assembler.register(assembly_c).unwrap();
+    assembler.register(assembly_a).unwrap();
 
     assert!(assembler.assemble().is_ok());
 }
 
-#[test]
-fn test_very_deep_dependency_chain() {
-    let monitor = Arc::new(NoopMonitor);
-    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
-
-    struct Service1;
-    struct Service2;
-    struct Service3;
-    struct Service4;
-    struct Service5;
+struct Service1;
+struct Service2;
+struct Service3;
+struct Service4;
+struct Service5;
 
-    // Chain: 1 <- 2 <- 3 <- 4 <- 5
+/// Registers the chain Layer1 <- Layer2 <- Layer3 <- Layer4 <- Layer5 in random order
+fn register_deep_chain(assembler: &Assembler) {
     let assembly_1 = Arc::new(
-        MockServiceAssembly::new("Layer1").with_provides(vec![TypeKey::new::<Service1>()])
+        MockServiceAssembly::new("Layer1").provide(Service1)
     );
     let assembly_2 = Arc::new(
         MockServiceAssembly::new("Layer2")
-            .with_provides(vec![TypeKey::new::<Service2>()])
+            .provide(Service2)
             .with_requires(vec![TypeKey::new::<Service1>()])
     );
     let assembly_3 = Arc::new(
         MockServiceAssembly::new("Layer3")
-            .with_provides(vec![TypeKey::new::<Service3>()])
+            .provide(Service3)
             .with_requires(vec![TypeKey::new::<Service2>()])
     );
     let assembly_4 = Arc::new(
         MockServiceAssembly::new("Layer4")
-            .with_provides(vec![TypeKey::new::<Service4>()])
+            .provide(Service4)
             .with_requires(vec![TypeKey::new::<Service3>()])
     );
     let assembly_5 = Arc::new(
         MockServiceAssembly::new("Layer5")
-            .with_provides(vec![TypeKey::new::<Service5>()])
+            .provide(Service5)
             .with_requires(vec![TypeKey::new::<Service4>()])
     );
 
     // Register in random order
-    assembler.register(assembly_3);
-    assembler.register(assembly_5);
-    assembler.register(assembly_1);
-    assembler.register(assembly_4);
-    assembler.register(assembly_2);
+    assembler.register(assembly_3).unwrap();
+    assembler.register(assembly_5).unwrap();
+    assembler.register(assembly_1).unwrap();
+    assembler.register(assembly_4).unwrap();
+    assembler.register(assembly_2).unwrap();
+}
+
+#[test]
+fn test_very_deep_dependency_chain() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    register_deep_chain(&assembler);
 
     assert!(assembler.assemble().is_ok());
 }
 
+#[test]
+fn test_initialization_order_of_deep_chain() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    register_deep_chain(&assembler);
+    assert!(assembler.initialization_order().is_empty());
+
+    assembler.assemble().unwrap();
+
+    let order = assembler.initialization_order();
+    assert_eq!(order, vec!["Layer1", "Layer2", "Layer3", "Layer4", "Layer5"]);
+    let layer1 = order.iter().position(|n| n == "Layer1").unwrap();
+    let layer5 = order.iter().position(|n| n == "Layer5").unwrap();
+    assert!(layer1 < layer5);
+}
+
+#[test]
+fn test_subgraph_of_deep_chain() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    register_deep_chain(&assembler);
+    assembler.assemble().unwrap();
+
+    // Edges point to required assemblies, so Layer3 reaches the layers below it
+    let subgraph = assembler.last_graph().unwrap().subgraph(&["Layer3"]);
+    let mut ids: Vec<&String> = subgraph.vertices.keys().collect();
+    ids.sort();
+    assert_eq!(ids, vec!["Layer1", "Layer2", "Layer3"]);
+    assert_eq!(subgraph.edge_count(), 2);
+    assert_eq!(subgraph.topological_sort().sorted_order, vec!["Layer3", "Layer2", "Layer1"]);
+}
+
 // ============================================================================
 // Edge Cases
 // ============================================================================
@@ -1346,27 +2367,55 @@ fn test_multiple_calls_to_assemble() {
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
     #[assembly(provides = [ServiceA])]
-    struct MultiCallAssembly {}
+    struct MultiCallAssembly {
+        inits: Arc<AtomicUsize>,
+    }
     impl ServiceAssembly for MultiCallAssembly {
         fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            self.inits.fetch_add(1, Ordering::SeqCst);
             context.registry.register(Arc::new(ServiceA));
             Ok(())
         }
     }
 
-    assembler.register(Arc::new(MultiCallAssembly {}));
+    let inits = Arc::new(AtomicUsize::new(0));
+    assembler.register(Arc::new(MultiCallAssembly { inits: inits.clone() })).unwrap();
     assert!(assembler.assemble().is_ok());
 
-    // Second call should also work
-    assert!(assembler.assemble().is_ok());
+    // The second call is rejected without re-running the lifecycle
+    let err = assembler.assemble().unwrap_err();
+    assert!(err.to_string().contains("Cannot assemble again"));
+    assert_eq!(inits.load(Ordering::SeqCst), 1);
+    assert_eq!(assembler.initialization_order(), vec!["assembly::MultiCallAssembly"]);
+
+    assembler.shutdown().unwrap();
+    let err = assembler.assemble().unwrap_err();
+    assert!(err.to_string().contains("Cannot assemble after shutdown"));
+    assert_eq!(inits.load(Ordering::SeqCst), 1);
 }
 
 #[test]
-fn test_assembly_requires_but_not_provides() {
+fn test_assemble_retried_after_failure() {
     let monitor = Arc::new(NoopMonitor);
     let assembler = Assembler::new(monitor, RuntimeMode::Debug);
 
-    // Pure provider
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Consumer").with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+    assert!(assembler.assemble().is_err());
+
+    // A failed assemble leaves the assembler open for registration
+    assembler.register(Arc::new(MockServiceAssembly::new("Provider").provide(ServiceA))).unwrap();
+    assembler.assemble().unwrap();
+    assert_eq!(assembler.initialization_order(), vec!["Provider", "Consumer"]);
+}
+
+#[test]
+fn test_assembly_requires_but_not_provides() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    // Pure provider
     #[assembly(provides = [ServiceA])]
     struct Provider {}
     impl ServiceAssembly for Provider {
@@ -1386,8 +2435,8 @@ fn test_assembly_requires_but_not_provides() {
         }
     }
 
-    assembler.register(Arc::new(Provider {}));
-    assembler.register(Arc::new(Consumer {}));
+    assembler.register(Arc::new(Provider {})).unwrap();
+    assembler.register(Arc::new(Consumer {})).unwrap();
     assert!(assembler.assemble().is_ok());
 }
 
@@ -1406,7 +2455,7 @@ fn test_assembly_provides_but_not_requires() {
         }
     }
 
-    assembler.register(Arc::new(PureProvider {}));
+    assembler.register(Arc::new(PureProvider {})).unwrap();
     assert!(assembler.assemble().is_ok());
 }
 
@@ -1438,7 +2487,7 @@ fn test_registry_state_persists_across_phases() {
         }
     }
 
-    assembler.register(Arc::new(PersistenceTestAssembly {}));
+    assembler.register(Arc::new(PersistenceTestAssembly {})).unwrap();
     assert!(assembler.assemble().is_ok());
 }
 
@@ -1467,8 +2516,8 @@ fn test_multiple_assemblies_share_registry() {
         }
     }
 
-    assembler.register(Arc::new(FirstRegistry {}));
-    assembler.register(Arc::new(SecondRegistry {}));
+    assembler.register(Arc::new(FirstRegistry {})).unwrap();
+    assembler.register(Arc::new(SecondRegistry {})).unwrap();
     assert!(assembler.assemble().is_ok());
 }
 
@@ -1491,7 +2540,7 @@ fn test_assembly_can_resolve_services_in_start() {
         }
     }
 
-    assembler.register(Arc::new(StartResolveAssembly {}));
+    assembler.register(Arc::new(StartResolveAssembly {})).unwrap();
     assert!(assembler.assemble().is_ok());
 }
 
@@ -1510,9 +2559,10 @@ fn test_macro_empty_provides_and_requires() {
     }
 
     let assembly = MinimalAssembly {};
-    assert_eq!(assembly.name(), "MinimalAssembly");
+    assert_eq!(assembly.name(), "assembly::MinimalAssembly");
     assert!(assembly.provides().is_empty());
     assert!(assembly.requires().is_empty());
+    assert!(MinimalAssembly::PROVIDES.is_empty());
 }
 
 #[test]
@@ -1553,3 +2603,2794 @@ fn test_macro_with_many_types() {
     assert_eq!(assembly.provides().len(), 3);
     assert_eq!(assembly.requires().len(), 5);
 }
+
+#[test]
+fn test_macro_generic_struct() {
+    #[assembly(name = "GenericCache", provides = [T], requires = [ServiceA])]
+    struct GenericCacheAssembly<T: Send + Sync + 'static> {
+        _marker: std::marker::PhantomData<T>,
+    }
+    impl<T: Send + Sync + 'static> ServiceAssembly for GenericCacheAssembly<T> {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    let assembly = GenericCacheAssembly::<ServiceB> {
+        _marker: std::marker::PhantomData,
+    };
+    assert_eq!(assembly.name(), "GenericCache");
+    assert_eq!(assembly.provides(), vec![TypeKey::new::<ServiceB>()]);
+    assert_eq!(assembly.requires(), vec![TypeKey::new::<ServiceA>()]);
+}
+
+#[test]
+fn test_macro_generic_struct_with_where_clause() {
+    #[assembly(provides = [T])]
+    struct StoreAssembly<T>
+    where
+        T: Default + Send + Sync + 'static,
+    {
+        _marker: std::marker::PhantomData<T>,
+    }
+    impl<T> ServiceAssembly for StoreAssembly<T>
+    where
+        T: Default + Send + Sync + 'static,
+    {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(T::default()));
+            Ok(())
+        }
+    }
+
+    let assembly = StoreAssembly::<String> {
+        _marker: std::marker::PhantomData,
+    };
+    assert_eq!(assembly.name(), "assembly::StoreAssembly");
+    assert_eq!(assembly.provides(), vec![TypeKey::new::<String>()]);
+}
+
+#[test]
+fn test_macro_named_provides() {
+    #[assembly(provides = [(ServiceA, "primary"), (ServiceA, "replica"), ServiceB])]
+    struct NamedProvidesAssembly {}
+    impl ServiceAssembly for NamedProvidesAssembly {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    let assembly = NamedProvidesAssembly {};
+    assert_eq!(
+        assembly.provides(),
+        vec![TypeKey::new::<ServiceA>(), TypeKey::new::<ServiceB>()]
+    );
+    assert_eq!(
+        assembly.provides_named(),
+        vec![
+            (TypeKey::new::<ServiceA>(), Some("primary".to_string())),
+            (TypeKey::new::<ServiceA>(), Some("replica".to_string())),
+            (TypeKey::new::<ServiceB>(), None),
+        ]
+    );
+}
+
+#[test]
+fn test_provides_named_defaults_to_unnamed() {
+    let assembly = SimpleAssembly {};
+    assert_eq!(
+        assembly.provides_named(),
+        vec![(TypeKey::new::<ServiceA>(), None)]
+    );
+}
+
+mod billing {
+    use super::*;
+
+    #[assembly(provides = [ServiceA])]
+    pub struct ApiAssembly {}
+
+    impl ServiceAssembly for ApiAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+}
+
+mod shipping {
+    use super::*;
+
+    #[assembly(provides = [ServiceB])]
+    pub struct ApiAssembly {}
+
+    impl ServiceAssembly for ApiAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceB));
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_macro_default_name_includes_module_path() {
+    let billing = billing::ApiAssembly {};
+    let shipping = shipping::ApiAssembly {};
+    assert_eq!(billing.name(), "assembly::billing::ApiAssembly");
+    assert_eq!(shipping.name(), "assembly::shipping::ApiAssembly");
+
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    assembler.register(Arc::new(billing)).unwrap();
+    assembler.register(Arc::new(shipping)).unwrap();
+    assembler.assemble().unwrap();
+    assert_eq!(assembler.initialization_order().len(), 2);
+}
+
+fn init_service_a(_assembly: &InitFnAssembly, context: &MutableAssemblyContext) -> Result<()> {
+    context.registry.register(Arc::new(ServiceA));
+    Ok(())
+}
+
+#[assembly(init = init_service_a, provides = [ServiceA])]
+struct InitFnAssembly {}
+
+#[test]
+fn test_macro_init_fn_generates_service_assembly() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(InitFnAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    assert!(assembler.registry().contains::<ServiceA>());
+    assert_eq!(InitFnAssembly {}.name(), "assembly::InitFnAssembly");
+}
+
+// `Result` is std's here and neither the assembly traits nor `TypeKey` are imported
+mod plain_imports {
+    use super::ServiceA;
+    use assemblr::assembly::AssemblyError;
+    use assembly_macros::assembly;
+    use std::sync::Arc;
+
+    pub fn init_plain(
+        _assembly: &PlainInitAssembly,
+        context: &assemblr::assembly::MutableAssemblyContext,
+    ) -> Result<(), AssemblyError> {
+        context.registry.register(Arc::new(ServiceA));
+        Ok(())
+    }
+
+    #[assembly(init = init_plain, provides = [ServiceA])]
+    pub struct PlainInitAssembly {}
+}
+
+#[test]
+fn test_macro_init_fn_uses_qualified_paths() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(plain_imports::PlainInitAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    assert!(assembler.registry().contains::<ServiceA>());
+}
+
+// ============================================================================
+// Reload Tests
+// ============================================================================
+
+struct VersionedService {
+    version: u32,
+}
+
+#[assembly(provides = [VersionedService])]
+struct VersionedAssembly {
+    version: u32,
+    registry: Arc<Mutex<Option<Arc<ServiceRegistry>>>>,
+}
+
+impl ServiceAssembly for VersionedAssembly {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        context.registry.register(Arc::new(VersionedService {
+            version: self.version,
+        }));
+        Ok(())
+    }
+
+    fn start(&self, context: &AssemblyContext) -> Result<()> {
+        *self.registry.lock().unwrap() = Some(context.registry.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_reload_replaces_service_instance() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    let registry = Arc::new(Mutex::new(None));
+
+    assembler.register(Arc::new(VersionedAssembly {
+        version: 1,
+        registry: registry.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+
+    let shared = registry.lock().unwrap().clone().unwrap();
+    let before = shared.resolve::<VersionedService>();
+    assert_eq!(before.version, 1);
+
+    assembler
+        .reload(
+            "assembly::VersionedAssembly",
+            Arc::new(VersionedAssembly {
+                version: 2,
+                registry: registry.clone(),
+            }),
+        )
+        .unwrap();
+
+    let after = shared.resolve::<VersionedService>();
+    assert_eq!(after.version, 2);
+    assert!(!Arc::ptr_eq(&before, &after));
+}
+
+#[test]
+fn test_reload_runs_old_shutdown_and_new_lifecycle() {
+    let old_events = Arc::new(Mutex::new(Vec::new()));
+    let new_events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(LifecycleTrackingAssembly::new(old_events.clone()))).unwrap();
+    assembler.assemble().unwrap();
+
+    assembler
+        .reload(
+            "assembly::LifecycleTrackingAssembly",
+            Arc::new(LifecycleTrackingAssembly::new(new_events.clone())),
+        )
+        .unwrap();
+
+    assert_eq!(
+        *old_events.lock().unwrap(),
+        vec!["init", "prepare", "start", "finalize", "shutdown"]
+    );
+    assert_eq!(*new_events.lock().unwrap(), vec!["init", "prepare", "start"]);
+}
+
+#[test]
+fn test_reload_rejects_changed_provides() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Core").provide(ServiceA),
+    )).unwrap();
+    assembler.assemble().unwrap();
+
+    let replacement = Arc::new(
+        MockServiceAssembly::new("Core").provide(ServiceB),
+    );
+    let result = assembler.reload("Core", replacement);
+    assert!(result.is_err());
+}
+
+#[assembly(name = "Dashboard", requires = [VersionedService])]
+struct DashboardAssembly {
+    registry: Mutex<Option<Arc<ServiceRegistry>>>,
+}
+
+impl ServiceAssembly for DashboardAssembly {
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        Ok(())
+    }
+
+    fn start(&self, context: &AssemblyContext) -> Result<()> {
+        *self.registry.lock().unwrap() = Some(context.registry.clone());
+        Ok(())
+    }
+}
+
+impl DashboardAssembly {
+    fn version(&self) -> u32 {
+        let registry = self.registry.lock().unwrap().clone().unwrap();
+        registry.resolve::<VersionedService>().version
+    }
+}
+
+#[test]
+fn test_reload_assembly_swaps_provider_for_dependents() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    let dashboard = Arc::new(DashboardAssembly { registry: Mutex::new(None) });
+
+    assembler.register(Arc::new(VersionedAssembly {
+        version: 1,
+        registry: Arc::new(Mutex::new(None)),
+    })).unwrap();
+    assembler.register(dashboard.clone()).unwrap();
+    assembler.assemble().unwrap();
+    assert_eq!(dashboard.version(), 1);
+
+    assembler
+        .reload_assembly(
+            "assembly::VersionedAssembly",
+            Arc::new(VersionedAssembly {
+                version: 2,
+                registry: Arc::new(Mutex::new(None)),
+            }),
+        )
+        .unwrap();
+
+    assert_eq!(dashboard.version(), 2);
+    assert_eq!(
+        assembler.initialization_order(),
+        vec!["assembly::VersionedAssembly", "Dashboard"]
+    );
+}
+
+#[test]
+fn test_reload_assembly_rejects_changed_requires() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(MockServiceAssembly::new("Base").provide(ServiceA))).unwrap();
+    assembler.register(Arc::new(MockServiceAssembly::new("Core").provide(ServiceB))).unwrap();
+    assembler.assemble().unwrap();
+
+    let replacement = Arc::new(
+        MockServiceAssembly::new("Core")
+            .provide(ServiceB)
+            .with_requires(vec![TypeKey::new::<ServiceA>()]),
+    );
+    let err = assembler.reload_assembly("Core", replacement).unwrap_err();
+    assert!(err.to_string().contains("required services differ"));
+    assert!(assembler.registry().contains::<ServiceB>());
+}
+
+#[test]
+fn test_failed_reload_assembly_keeps_old_provisions() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(name = "Configurable", provides = [ServiceA])]
+    struct BrokenConfigurableAssembly {}
+    impl ServiceAssembly for BrokenConfigurableAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+        fn prepare(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Err(AssemblyError::GeneralError("prepare failed".to_string()))
+        }
+    }
+
+    // The original reports its cache through dynamic_provides
+    assembler.register(Arc::new(ConfigurableAssembly::new(true))).unwrap();
+    assembler.assemble().unwrap();
+    let before = assembler.registry().resolve::<ServiceA>();
+
+    let err = assembler
+        .reload_assembly("Configurable", Arc::new(BrokenConfigurableAssembly {}))
+        .unwrap_err();
+    assert!(matches!(&err, AssemblyError::PhaseFailure { phase: Phase::Prepare, .. }));
+
+    // Nothing of the failed swap remains: the old services, including dynamic ones, are back
+    let registry = assembler.registry();
+    assert!(Arc::ptr_eq(&before, &registry.resolve::<ServiceA>()));
+    assert!(registry.contains::<Box<dyn Cache>>());
+    assert_eq!(
+        assembler.services_provided_by("Configurable"),
+        vec![TypeKey::new::<ServiceA>(), TypeKey::new::<Box<dyn Cache>>()]
+    );
+    assert_eq!(
+        assembler.provider_of(&TypeKey::new::<Box<dyn Cache>>()),
+        Some("Configurable".to_string())
+    );
+}
+
+#[test]
+fn test_reload_unknown_assembly_fails() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    let result = assembler.reload("Missing", Arc::new(MockServiceAssembly::new("Missing")));
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("Missing"));
+}
+
+#[test]
+fn test_reload_rejects_renamed_replacement() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    assembler.register(Arc::new(MockServiceAssembly::new("Original").provide(ServiceA))).unwrap();
+    assembler.assemble().unwrap();
+
+    let err = assembler
+        .reload("Original", Arc::new(MockServiceAssembly::new("Renamed").provide(ServiceA)))
+        .unwrap_err();
+    assert!(err.to_string().contains("the replacement is named 'Renamed'"));
+    assert_eq!(assembler.initialization_order(), vec!["Original"]);
+}
+
+#[test]
+fn test_reload_teardown_failure_keeps_replacement() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    let finalized = Arc::new(AtomicUsize::new(0));
+
+    #[assembly(name = "Flaky", provides = [ServiceA])]
+    struct FlakyAssembly {
+        finalized: Arc<AtomicUsize>,
+    }
+    impl ServiceAssembly for FlakyAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+        fn finalize(&self) -> Result<()> {
+            self.finalized.fetch_add(1, Ordering::SeqCst);
+            Err(AssemblyError::GeneralError("finalize failed".to_string()))
+        }
+    }
+
+    assembler.register(Arc::new(FlakyAssembly { finalized: finalized.clone() })).unwrap();
+    assembler.assemble().unwrap();
+
+    let err = assembler
+        .reload("Flaky", Arc::new(MockServiceAssembly::new("Flaky").provide(ServiceA)))
+        .unwrap_err();
+    assert!(matches!(&err, AssemblyError::ShutdownErrors(errors) if errors.len() == 1));
+    assert_eq!(assembler.initialization_order(), vec!["Flaky"]);
+    assert!(assembler.registry().contains::<ServiceA>());
+
+    // Only the replacement is torn down on shutdown
+    assembler.shutdown().unwrap();
+    assert_eq!(finalized.load(Ordering::SeqCst), 1);
+}
+
+#[assembly(name = "assembly::VersionedAssembly", provides = [VersionedService])]
+struct FailingInitVersionedAssembly {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssembly for FailingInitVersionedAssembly {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        self.events.lock().unwrap().push("init".to_string());
+        context.registry.register(Arc::new(ServiceB));
+        Err(AssemblyError::GeneralError("init failed".to_string()))
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        self.events.lock().unwrap().push("shutdown".to_string());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_reload_init_failure_keeps_old_instance() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(VersionedAssembly {
+        version: 1,
+        registry: Arc::new(Mutex::new(None)),
+    })).unwrap();
+    assembler.assemble().unwrap();
+
+    let err = assembler
+        .reload(
+            "assembly::VersionedAssembly",
+            Arc::new(FailingInitVersionedAssembly { events: events.clone() }),
+        )
+        .unwrap_err();
+    assert!(matches!(&err, AssemblyError::PhaseFailure { phase: Phase::Init, .. }));
+
+    // The old instance and its service stay in place; the replacement's registrations are undone
+    assert_eq!(assembler.initialization_order(), vec!["assembly::VersionedAssembly"]);
+    assert_eq!(assembler.registry().resolve::<VersionedService>().version, 1);
+    assert!(!assembler.registry().contains::<ServiceB>());
+
+    // The discarded replacement is not torn down
+    assembler.shutdown().unwrap();
+    assert_eq!(*events.lock().unwrap(), vec!["init"]);
+}
+
+// ============================================================================
+// Conditional Assembly Tests
+// ============================================================================
+
+trait PaymentGateway: Send + Sync {
+    fn _provider(&self) -> &str;
+}
+
+struct MockPaymentGateway;
+
+impl PaymentGateway for MockPaymentGateway {
+    fn _provider(&self) -> &str {
+        "mock"
+    }
+}
+
+struct LivePaymentGateway;
+
+impl PaymentGateway for LivePaymentGateway {
+    fn _provider(&self) -> &str {
+        "live"
+    }
+}
+
+#[assembly(name = "MockPaymentAssembly", provides = [Box<dyn PaymentGateway>])]
+struct MockPaymentAssembly {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssembly for MockPaymentAssembly {
+    fn enabled_for(&self, mode: RuntimeMode) -> bool {
+        mode != RuntimeMode::Production
+    }
+
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        self.events.lock().unwrap().push("mock".to_string());
+        context
+            .registry
+            .register(Arc::new(Box::new(MockPaymentGateway) as Box<dyn PaymentGateway>));
+        Ok(())
+    }
+}
+
+#[assembly(name = "LivePaymentAssembly", provides = [Box<dyn PaymentGateway>])]
+struct LivePaymentAssembly {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssembly for LivePaymentAssembly {
+    fn enabled_for(&self, mode: RuntimeMode) -> bool {
+        mode == RuntimeMode::Production
+    }
+
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        self.events.lock().unwrap().push("live".to_string());
+        context
+            .registry
+            .register(Arc::new(Box::new(LivePaymentGateway) as Box<dyn PaymentGateway>));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_only_assembly_enabled_for_mode_runs() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Production);
+
+    assembler.register(Arc::new(LivePaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(MockPaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(*events.lock().unwrap(), vec!["live"]);
+}
+
+#[test]
+fn test_disabled_assembly_runs_in_other_mode() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Development);
+
+    assembler.register(Arc::new(LivePaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(MockPaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(*events.lock().unwrap(), vec!["mock"]);
+}
+
+#[test]
+fn test_disabled_assembly_does_not_provide_dependencies() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Production);
+
+    assembler.register(Arc::new(MockPaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Checkout")
+            .with_requires(vec![TypeKey::new::<Box<dyn PaymentGateway>>()]),
+    )).unwrap();
+
+    let result = assembler.assemble();
+    assert!(matches!(result, Err(AssemblyError::MissingDependencies(_))));
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_providers_enabled_in_different_modes_are_not_duplicates() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(CapturingMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Production);
+
+    // Both provide the gateway, but only the live provider is enabled in Production
+    assembler.register(Arc::new(LivePaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(MockPaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Checkout")
+            .with_requires(vec![TypeKey::new::<Box<dyn PaymentGateway>>()]),
+    )).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(*events.lock().unwrap(), vec!["live"]);
+    assert_eq!(
+        assembler.provider_of(&TypeKey::new::<Box<dyn PaymentGateway>>()),
+        Some("LivePaymentAssembly".to_string())
+    );
+    assert!(monitor.messages_at_level(Level::Warn).is_empty());
+    assert!(monitor.messages_at_level(Level::Error).is_empty());
+}
+
+// ============================================================================
+// Metrics Tests
+// ============================================================================
+
+#[test]
+fn test_assemble_with_metrics_records_start_duration() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(provides = [ServiceA])]
+    struct SlowStartAssembly {}
+    impl ServiceAssembly for SlowStartAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+        fn start(&self, _context: &AssemblyContext) -> Result<()> {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(SlowStartAssembly {})).unwrap();
+    let metrics = assembler.assemble_with_metrics().unwrap();
+
+    let durations = metrics.get("assembly::SlowStartAssembly").unwrap();
+    assert!(durations.start >= std::time::Duration::from_millis(10));
+    assert!(durations.total() >= durations.start);
+    assert!(metrics.total >= durations.total());
+}
+
+#[test]
+fn test_assemble_with_metrics_covers_all_assemblies() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Provider").provide(ServiceA),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Consumer").with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+
+    let metrics = assembler.assemble_with_metrics().unwrap();
+    assert_eq!(metrics.assemblies.len(), 2);
+    assert!(metrics.get("Provider").is_some());
+    assert!(metrics.get("Consumer").is_some());
+    assert!(metrics.get("Unknown").is_none());
+}
+
+// ============================================================================
+// Registration Observer Tests
+// ============================================================================
+
+#[test]
+fn test_registration_events_name_registering_assembly() {
+    let monitor = Arc::new(MockLogMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Debug);
+
+    #[assembly(provides = [ServiceA])]
+    struct RegisteringAssembly {}
+    impl ServiceAssembly for RegisteringAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    #[assembly(requires = [ServiceA])]
+    struct PreparingAssembly {}
+    impl ServiceAssembly for PreparingAssembly {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+        fn prepare(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceB));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(PreparingAssembly {})).unwrap();
+    assembler.register(Arc::new(RegisteringAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let registrations: Vec<String> = monitor
+        .get_messages()
+        .into_iter()
+        .filter(|m| m.contains("Registered:"))
+        .collect();
+    assert_eq!(registrations.len(), 2);
+    assert!(registrations[0].contains("ServiceA"));
+    assert!(registrations[0].contains("'assembly::RegisteringAssembly'"));
+    assert!(registrations[0].contains("init"));
+    assert!(registrations[1].contains("ServiceB"));
+    assert!(registrations[1].contains("'assembly::PreparingAssembly'"));
+    assert!(registrations[1].contains("prepare"));
+}
+
+// ============================================================================
+// Lifecycle Observer Tests
+// ============================================================================
+
+struct RecordingObserver {
+    events: Mutex<Vec<String>>,
+}
+
+impl RecordingObserver {
+    fn new() -> Self {
+        Self {
+            events: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn events(&self) -> Vec<String> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl LifecycleObserver for RecordingObserver {
+    fn on_phase_start(&self, assembly: &str, phase: Phase) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("start:{}:{}", assembly, phase));
+    }
+
+    fn on_phase_end(&self, assembly: &str, phase: Phase, result: &Result<()>) {
+        let outcome = if result.is_ok() { "ok" } else { "err" };
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("end:{}:{}:{}", assembly, phase, outcome));
+    }
+}
+
+#[test]
+fn test_observer_receives_lifecycle_events_in_order() {
+    let observer = Arc::new(RecordingObserver::new());
+    let monitor = Arc::new(NoopMonitor);
+    let assembler =
+        Assembler::new(monitor, RuntimeMode::Debug).with_observer(observer.clone());
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+    assembler.shutdown().unwrap();
+
+    assert_eq!(
+        observer.events(),
+        vec![
+            "start:assembly::SimpleAssembly:configure",
+            "end:assembly::SimpleAssembly:configure:ok",
+            "start:assembly::SimpleAssembly:init",
+            "end:assembly::SimpleAssembly:init:ok",
+            "start:assembly::SimpleAssembly:prepare",
+            "end:assembly::SimpleAssembly:prepare:ok",
+            "start:assembly::SimpleAssembly:start",
+            "end:assembly::SimpleAssembly:start:ok",
+            "start:assembly::SimpleAssembly:finalize",
+            "end:assembly::SimpleAssembly:finalize:ok",
+            "start:assembly::SimpleAssembly:shutdown",
+            "end:assembly::SimpleAssembly:shutdown:ok",
+        ]
+    );
+}
+
+#[test]
+fn test_observer_receives_failed_phase_result() {
+    let observer = Arc::new(RecordingObserver::new());
+    let monitor = Arc::new(NoopMonitor);
+    let assembler =
+        Assembler::new(monitor, RuntimeMode::Debug).with_observer(observer.clone());
+
+    assembler.register(Arc::new(FailingAssembly {})).unwrap();
+    assert!(assembler.assemble().is_err());
+
+    assert_eq!(
+        observer.events(),
+        vec![
+            "start:assembly::FailingAssembly:configure",
+            "end:assembly::FailingAssembly:configure:ok",
+            "start:assembly::FailingAssembly:init",
+            "end:assembly::FailingAssembly:init:err",
+        ]
+    );
+}
+
+type ProgressEvents = Mutex<Vec<(Phase, usize, usize)>>;
+
+#[derive(Default)]
+struct ProgressObserver {
+    progress: ProgressEvents,
+}
+
+impl LifecycleObserver for ProgressObserver {
+    fn on_phase_start(&self, _assembly: &str, _phase: Phase) {}
+
+    fn on_phase_end(&self, _assembly: &str, _phase: Phase, _result: &Result<()>) {}
+
+    fn on_progress(&self, _assembly: &str, phase: Phase, index: usize, total: usize) {
+        self.progress.lock().unwrap().push((phase, index, total));
+    }
+}
+
+#[test]
+fn test_observer_receives_progress_per_phase() {
+    let observer = Arc::new(ProgressObserver::default());
+    let monitor = Arc::new(NoopMonitor);
+    let assembler =
+        Assembler::new(monitor, RuntimeMode::Debug).with_observer(observer.clone());
+
+    assembler.register(Arc::new(MockServiceAssembly::new("One"))).unwrap();
+    assembler.register(Arc::new(MockServiceAssembly::new("Two"))).unwrap();
+    assembler.register(Arc::new(MockServiceAssembly::new("Three"))).unwrap();
+    assembler.assemble().unwrap();
+
+    let progress = observer.progress.lock().unwrap();
+    let mut expected = Vec::new();
+    for phase in [Phase::Configure, Phase::Init, Phase::Prepare, Phase::Start] {
+        for index in 0..3 {
+            expected.push((phase, index, 3));
+        }
+    }
+    assert_eq!(*progress, expected);
+}
+
+#[test]
+fn test_phase_display() {
+    assert_eq!(Phase::Configure.to_string(), "configure");
+    assert_eq!(Phase::Init.to_string(), "init");
+    assert_eq!(Phase::Prepare.to_string(), "prepare");
+    assert_eq!(Phase::Start.to_string(), "start");
+    assert_eq!(Phase::Finalize.to_string(), "finalize");
+    assert_eq!(Phase::Shutdown.to_string(), "shutdown");
+}
+
+// ============================================================================
+// Validation Tests
+// ============================================================================
+
+#[test]
+fn test_validate_returns_resolved_order() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Consumer").with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone()))).unwrap();
+
+    let order = assembler.validate().unwrap();
+    assert_eq!(order, vec!["assembly::LifecycleTrackingAssembly", "Consumer"]);
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_validate_missing_dependency_without_init() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone()))).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("NeedsDependency").with_requires(vec![TypeKey::new::<ServiceB>()]),
+    )).unwrap();
+
+    let result = assembler.validate();
+    assert!(matches!(result, Err(AssemblyError::MissingDependencies(_))));
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("Required assembly not found")
+    );
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_validate_cyclic_dependency_without_init() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(provides = [ServiceA], requires = [ServiceB])]
+    struct CycleFirst {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for CycleFirst {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("first".to_string());
+            Ok(())
+        }
+    }
+
+    #[assembly(provides = [ServiceB], requires = [ServiceA])]
+    struct CycleSecond {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for CycleSecond {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("second".to_string());
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(CycleFirst {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(CycleSecond {
+        events: events.clone(),
+    })).unwrap();
+
+    let result = assembler.validate();
+    assert!(matches!(result, Err(AssemblyError::CyclicDependency { .. })));
+    assert!(result.unwrap_err().to_string().contains("Cyclic dependency"));
+    assert!(events.lock().unwrap().is_empty());
+}
+
+// ============================================================================
+// Concurrency Tests
+// ============================================================================
+
+struct ConcurrencyProbe {
+    active: Mutex<usize>,
+    peak: Mutex<usize>,
+}
+
+impl ConcurrencyProbe {
+    fn new() -> Self {
+        Self {
+            active: Mutex::new(0),
+            peak: Mutex::new(0),
+        }
+    }
+
+    fn enter(&self) {
+        let mut active = self.active.lock().unwrap();
+        *active += 1;
+        let mut peak = self.peak.lock().unwrap();
+        *peak = (*peak).max(*active);
+    }
+
+    fn exit(&self) {
+        *self.active.lock().unwrap() -= 1;
+    }
+
+    fn peak(&self) -> usize {
+        *self.peak.lock().unwrap()
+    }
+}
+
+struct ProbedAssembly {
+    name: String,
+    probe: Arc<ConcurrencyProbe>,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssemblyBase for ProbedAssembly {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl ServiceAssembly for ProbedAssembly {
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        self.probe.enter();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        self.events.lock().unwrap().push(self.name.clone());
+        self.probe.exit();
+        Ok(())
+    }
+}
+
+#[test]
+fn test_max_concurrency_limits_parallel_phase() {
+    let probe = Arc::new(ConcurrencyProbe::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug).with_max_concurrency(2);
+
+    for i in 0..8 {
+        assembler.register(Arc::new(ProbedAssembly {
+            name: format!("Worker{}", i),
+            probe: probe.clone(),
+            events: events.clone(),
+        })).unwrap();
+    }
+
+    assembler.assemble().unwrap();
+
+    assert_eq!(events.lock().unwrap().len(), 8);
+    assert!(probe.peak() <= 2, "peak concurrency was {}", probe.peak());
+}
+
+/// A barrier whose waiters give up after a timeout instead of blocking forever
+struct Rendezvous {
+    arrived: Mutex<usize>,
+    all_arrived: Condvar,
+    parties: usize,
+}
+
+impl Rendezvous {
+    fn new(parties: usize) -> Self {
+        Self {
+            arrived: Mutex::new(0),
+            all_arrived: Condvar::new(),
+            parties,
+        }
+    }
+
+    /// Returns true if every party arrived before the timeout
+    fn wait(&self, timeout: std::time::Duration) -> bool {
+        let mut arrived = self.arrived.lock().unwrap();
+        *arrived += 1;
+        self.all_arrived.notify_all();
+        let (arrived, _) = self
+            .all_arrived
+            .wait_timeout_while(arrived, timeout, |arrived| *arrived < self.parties)
+            .unwrap();
+        *arrived >= self.parties
+    }
+}
+
+struct RendezvousAssembly {
+    name: String,
+    rendezvous: Arc<Rendezvous>,
+    met: Arc<Mutex<Vec<bool>>>,
+}
+
+impl ServiceAssemblyBase for RendezvousAssembly {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl ServiceAssembly for RendezvousAssembly {
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        let met = self.rendezvous.wait(std::time::Duration::from_secs(10));
+        self.met.lock().unwrap().push(met);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_max_concurrency_runs_independent_assemblies_in_parallel() {
+    let rendezvous = Arc::new(Rendezvous::new(2));
+    let met = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug).with_max_concurrency(2);
+
+    // Each init only returns early once the other one is running at the same time
+    for i in 0..2 {
+        assembler.register(Arc::new(RendezvousAssembly {
+            name: format!("Peer{}", i),
+            rendezvous: rendezvous.clone(),
+            met: met.clone(),
+        })).unwrap();
+    }
+
+    assembler.assemble().unwrap();
+
+    assert_eq!(*met.lock().unwrap(), vec![true, true]);
+}
+
+#[test]
+fn test_parallel_assembly_respects_dependencies() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug).with_max_concurrency(4);
+
+    #[assembly(provides = [ServiceA])]
+    struct BaseAssembly {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for BaseAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            self.events.lock().unwrap().push("base".to_string());
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    #[assembly(provides = [ServiceB], requires = [ServiceA])]
+    struct LeftAssembly {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for LeftAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            let _a = context.registry.resolve::<ServiceA>();
+            self.events.lock().unwrap().push("left".to_string());
+            context.registry.register(Arc::new(ServiceB));
+            Ok(())
+        }
+    }
+
+    #[assembly(provides = [ServiceC], requires = [ServiceA])]
+    struct RightAssembly {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for RightAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            let _a = context.registry.resolve::<ServiceA>();
+            self.events.lock().unwrap().push("right".to_string());
+            context.registry.register(Arc::new(ServiceC));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(LeftAssembly { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(RightAssembly { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(BaseAssembly { events: events.clone() })).unwrap();
+
+    assembler.assemble().unwrap();
+
+    let tracked = events.lock().unwrap();
+    assert_eq!(tracked.len(), 3);
+    assert_eq!(tracked[0], "base");
+}
+
+#[test]
+fn test_parallel_assembly_reports_failure() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug).with_max_concurrency(2);
+
+    assembler.register(Arc::new(MockServiceAssembly::new("Healthy"))).unwrap();
+    assembler.register(Arc::new(FailingAssembly {})).unwrap();
+
+    let result = assembler.assemble();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("Intentional init failure"));
+}
+
+// ============================================================================
+// Duplicate Provider Tests
+// ============================================================================
+
+#[test]
+fn test_duplicate_provider_fails_in_production() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Production);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("FirstProvider").provide(ServiceA),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("SecondProvider").provide(ServiceA),
+    )).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    match &err {
+        AssemblyError::DuplicateProvider {
+            type_key,
+            assemblies,
+        } => {
+            assert_eq!(*type_key, TypeKey::new::<ServiceA>());
+            assert_eq!(*assemblies, vec!["FirstProvider", "SecondProvider"]);
+        }
+        other => panic!("Unexpected error: {}", other),
+    }
+    let msg = err.to_string();
+    assert!(msg.contains("FirstProvider"));
+    assert!(msg.contains("SecondProvider"));
+}
+
+#[test]
+fn test_duplicate_provider_warns_in_development() {
+    let monitor = Arc::new(MockLogMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Development);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("FirstProvider").provide(ServiceA),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("SecondProvider").provide(ServiceA),
+    )).unwrap();
+
+    assert!(assembler.assemble().is_ok());
+    let warning = monitor
+        .get_messages()
+        .into_iter()
+        .find(|m| m.starts_with("WARN"))
+        .unwrap();
+    assert!(warning.contains("FirstProvider"));
+    assert!(warning.contains("using 'SecondProvider'"));
+}
+
+#[test]
+fn test_highest_priority_provider_wins() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(CapturingMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Production);
+
+    #[assembly(name = "Preferred", provides = [ServiceA], priority = 10)]
+    struct PreferredProvider {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for PreferredProvider {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("Preferred".to_string());
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    #[assembly(name = "Fallback", provides = [ServiceA], priority = 1)]
+    struct FallbackProvider {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for FallbackProvider {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("Fallback".to_string());
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    // Register the preferred provider first so "last registered wins" would pick the other
+    assembler.register(Arc::new(PreferredProvider { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(FallbackProvider { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Consumer").with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(*events.lock().unwrap(), vec!["Preferred"]);
+    assert_eq!(assembler.initialization_order(), vec!["Preferred", "Consumer"]);
+    let info = monitor.messages_at_level(Level::Info);
+    assert!(info.iter().any(|m| {
+        m.contains("using 'Preferred' with priority 10") && m.contains("skipping Fallback")
+    }));
+}
+
+#[test]
+fn test_priority_macro_argument() {
+    #[assembly(priority = -5)]
+    struct Deprioritized {}
+
+    assert_eq!(Deprioritized {}.priority(), -5);
+    assert_eq!(MockServiceAssembly::new("Default").priority(), 0);
+}
+
+#[test]
+fn test_overriding_assembly_replaces_overridden() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(CapturingMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Development);
+
+    #[assembly(name = "DatabaseAssembly", provides = [ServiceA])]
+    struct DatabaseAssembly {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for DatabaseAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("DatabaseAssembly".to_string());
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    #[assembly(name = "MockDatabaseAssembly", provides = [ServiceA], overrides = ["DatabaseAssembly"])]
+    struct MockDatabaseAssembly {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for MockDatabaseAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("MockDatabaseAssembly".to_string());
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(DatabaseAssembly { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(MockDatabaseAssembly { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Consumer").with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(*events.lock().unwrap(), vec!["MockDatabaseAssembly"]);
+    assert_eq!(assembler.initialization_order(), vec!["MockDatabaseAssembly", "Consumer"]);
+    assert_eq!(
+        MockDatabaseAssembly { events: events.clone() }.overrides(),
+        vec!["DatabaseAssembly".to_string()]
+    );
+    let info = monitor.messages_at_level(Level::Info);
+    assert!(info.iter().any(|m| m.contains("Assembly 'DatabaseAssembly' is overridden by 'MockDatabaseAssembly'")));
+}
+
+#[test]
+fn test_override_by_default_name() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Development);
+
+    #[assembly(provides = [ServiceA])]
+    struct RealStorage {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for RealStorage {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("real".to_string());
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    // Default names include the module path
+    #[assembly(provides = [ServiceA], overrides = ["assembly::RealStorage"])]
+    struct MockStorage {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for MockStorage {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("mock".to_string());
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(RealStorage { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(MockStorage { events: events.clone() })).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(*events.lock().unwrap(), vec!["mock"]);
+    assert_eq!(assembler.initialization_order(), vec!["assembly::MockStorage"]);
+}
+
+#[test]
+fn test_unknown_override_is_rejected() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Development);
+
+    #[assembly(provides = [ServiceA])]
+    struct RealQueue {}
+    impl ServiceAssembly for RealQueue {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    // The short struct name does not match the default name "assembly::RealQueue"
+    #[assembly(provides = [ServiceA], overrides = ["RealQueue"])]
+    struct MockQueue {}
+    impl ServiceAssembly for MockQueue {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(RealQueue {})).unwrap();
+    assembler.register(Arc::new(MockQueue {})).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    match &err {
+        AssemblyError::UnknownOverride { assembly, overridden } => {
+            assert_eq!(assembly, "assembly::MockQueue");
+            assert_eq!(overridden, "RealQueue");
+        }
+        other => panic!("Unexpected error: {}", other),
+    }
+    assert!(assembler.initialization_order().is_empty());
+}
+
+// ============================================================================
+// Optional Dependency Tests
+// ============================================================================
+
+#[test]
+fn test_missing_optional_dependency_is_ignored() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(provides = [ServiceB], requires_optional = [ServiceA])]
+    struct OptionalConsumer {}
+    impl ServiceAssembly for OptionalConsumer {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceB));
+            Ok(())
+        }
+    }
+
+    let consumer = OptionalConsumer {};
+    assert!(consumer.requires().is_empty());
+    assert_eq!(consumer.optional_requires(), vec![TypeKey::new::<ServiceA>()]);
+
+    assembler.register(Arc::new(consumer)).unwrap();
+    assert!(assembler.assemble().is_ok());
+}
+
+#[test]
+fn test_optional_dependency_orders_initialization() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(provides = [ServiceB], requires_optional = [ServiceA])]
+    struct OptionalUser {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+    impl ServiceAssembly for OptionalUser {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            self.events.lock().unwrap().push("user".to_string());
+            context.registry.register(Arc::new(ServiceB));
+            Ok(())
+        }
+    }
+
+    // Register the consumer first to make sure ordering comes from the optional edge
+    assembler.register(Arc::new(OptionalUser {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone()))).unwrap();
+    assembler.assemble().unwrap();
+
+    let tracked = events.lock().unwrap();
+    let provider_pos = tracked.iter().position(|e| e == "init").unwrap();
+    let user_pos = tracked.iter().position(|e| e == "user").unwrap();
+    assert!(provider_pos < user_pos);
+}
+
+// ============================================================================
+// Restart Tests
+// ============================================================================
+
+#[test]
+fn test_restart_reruns_lifecycle() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(LifecycleTrackingAssembly::new(events.clone()))).unwrap();
+    assembler.assemble().unwrap();
+    assembler.restart().unwrap();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            "init", "prepare", "start", "finalize", "shutdown", "init", "prepare", "start"
+        ]
+    );
+}
+
+#[test]
+fn test_restart_clears_registry() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    let registry = Arc::new(Mutex::new(None));
+
+    assembler.register(Arc::new(VersionedAssembly {
+        version: 1,
+        registry: registry.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+    let before = registry.lock().unwrap().clone().unwrap().resolve::<VersionedService>();
+
+    assembler.restart().unwrap();
+    let after = registry.lock().unwrap().clone().unwrap().resolve::<VersionedService>();
+
+    // Re-initialization registered a fresh instance
+    assert!(!Arc::ptr_eq(&before, &after));
+}
+
+#[test]
+fn test_restart_preserves_registered_assemblies() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(LivePaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(MockPaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+    assembler.restart().unwrap();
+
+    // The disabled assembly is skipped both times, the enabled one runs twice
+    assert_eq!(*events.lock().unwrap(), vec!["mock", "mock"]);
+}
+
+// ============================================================================
+// Scoped Monitor Tests
+// ============================================================================
+
+#[test]
+fn test_context_monitor_prefixes_assembly_name() {
+    let monitor = Arc::new(MockLogMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Debug);
+
+    #[assembly(provides = [ServiceA])]
+    struct ScopedLogAssembly {}
+    impl ServiceAssembly for ScopedLogAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.log_monitor.info("init message");
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+        fn start(&self, context: &AssemblyContext) -> Result<()> {
+            context.log_monitor.warn("start message");
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(ScopedLogAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let messages = monitor.get_messages();
+    assert!(messages.contains(&"INFO: [assembly::ScopedLogAssembly] init message".to_string()));
+    assert!(messages.contains(&"WARN: [assembly::ScopedLogAssembly] start message".to_string()));
+}
+
+#[test]
+fn test_custom_monitor_builder() {
+    let monitor = Arc::new(MockLogMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Debug).with_monitor_builder(
+        Arc::new(|_name: &str, inner: Arc<dyn LogMonitor>| inner),
+    );
+
+    #[assembly]
+    struct UnscopedLogAssembly {}
+    impl ServiceAssembly for UnscopedLogAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.log_monitor.info("unscoped");
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(UnscopedLogAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let messages = monitor.get_messages();
+    assert!(messages.contains(&"INFO: unscoped".to_string()));
+}
+
+// ============================================================================
+// Structured Shutdown Error Tests
+// ============================================================================
+
+#[test]
+fn test_shutdown_errors_are_structured() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(provides = [ServiceA])]
+    struct BrokenFinalize {}
+    impl ServiceAssembly for BrokenFinalize {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+        fn finalize(&self) -> Result<()> {
+            Err(AssemblyError::GeneralError("Finalize error".to_string()))
+        }
+    }
+
+    #[assembly(provides = [ServiceB])]
+    struct BrokenShutdown {}
+    impl ServiceAssembly for BrokenShutdown {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceB));
+            Ok(())
+        }
+        fn shutdown(&self) -> Result<()> {
+            Err(AssemblyError::GeneralError("Shutdown error".to_string()))
+        }
+    }
+
+    assembler.register(Arc::new(BrokenFinalize {})).unwrap();
+    assembler.register(Arc::new(BrokenShutdown {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let err = assembler.shutdown().unwrap_err();
+    let AssemblyError::ShutdownErrors(errors) = &err else {
+        panic!("Unexpected error: {}", err);
+    };
+    assert_eq!(errors.len(), 2);
+    assert!(
+        errors
+            .iter()
+            .any(|(name, e)| name == "assembly::BrokenFinalize" && e.to_string() == "Finalize error")
+    );
+    assert!(
+        errors
+            .iter()
+            .any(|(name, e)| name == "assembly::BrokenShutdown" && e.to_string() == "Shutdown error")
+    );
+
+    let msg = err.to_string();
+    assert!(msg.contains("assembly::BrokenFinalize: Finalize error"));
+    assert!(msg.contains("assembly::BrokenShutdown: Shutdown error"));
+}
+
+#[test]
+fn test_shutdown_continues_past_panic() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(name = "Sibling", provides = [ServiceA])]
+    struct SiblingAssembly {
+        shut_down: Arc<Mutex<bool>>,
+    }
+    impl ServiceAssembly for SiblingAssembly {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+        fn shutdown(&self) -> Result<()> {
+            *self.shut_down.lock().unwrap() = true;
+            Ok(())
+        }
+    }
+
+    // Requiring the sibling shuts this assembly down first
+    #[assembly(name = "Panicking", requires = [ServiceA])]
+    struct PanickingShutdown {}
+    impl ServiceAssembly for PanickingShutdown {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+        fn shutdown(&self) -> Result<()> {
+            panic!("connection pool poisoned");
+        }
+    }
+
+    let shut_down = Arc::new(Mutex::new(false));
+    assembler.register(Arc::new(PanickingShutdown {})).unwrap();
+    assembler.register(Arc::new(SiblingAssembly { shut_down: shut_down.clone() })).unwrap();
+    assembler.assemble().unwrap();
+
+    let err = assembler.shutdown().unwrap_err();
+    assert!(*shut_down.lock().unwrap());
+    let AssemblyError::ShutdownErrors(errors) = &err else {
+        panic!("Unexpected error: {}", err);
+    };
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, "Panicking");
+    assert!(matches!(&errors[0].1, AssemblyError::GeneralError(msg)
+        if msg.contains("panicked during shutdown") && msg.contains("connection pool poisoned")));
+}
+
+// ============================================================================
+// Phase Failure Tests
+// ============================================================================
+
+#[test]
+fn test_init_failure_names_assembly_and_phase() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(FailingAssembly {})).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("assembly::FailingAssembly"), "Error message was: {}", msg);
+    assert!(msg.contains("init"), "Error message was: {}", msg);
+    assert!(msg.contains("Intentional init failure"), "Error message was: {}", msg);
+
+    match err {
+        AssemblyError::PhaseFailure {
+            assembly,
+            phase,
+            source,
+        } => {
+            assert_eq!(assembly, "assembly::FailingAssembly");
+            assert_eq!(phase, Phase::Init);
+            assert!(matches!(*source, AssemblyError::GeneralError(_)));
+        }
+        other => panic!("Unexpected error: {}", other),
+    }
+}
+
+#[test]
+fn test_start_failure_names_phase() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly]
+    struct BrokenStart {}
+    impl ServiceAssembly for BrokenStart {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+        fn start(&self, _context: &AssemblyContext) -> Result<()> {
+            Err(AssemblyError::GeneralError("Start failed".to_string()))
+        }
+    }
+
+    assembler.register(Arc::new(BrokenStart {})).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    assert!(matches!(
+        err,
+        AssemblyError::PhaseFailure {
+            phase: Phase::Start,
+            ..
+        }
+    ));
+    assert!(err.to_string().contains("'assembly::BrokenStart' failed during start"));
+}
+
+#[test]
+fn test_on_error_receives_failed_phase() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    let failures = Arc::new(Mutex::new(Vec::new()));
+
+    #[assembly]
+    struct BrokenInit {
+        failures: Arc<Mutex<Vec<(Phase, String)>>>,
+    }
+    impl ServiceAssembly for BrokenInit {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Err(AssemblyError::GeneralError("Init failed".to_string()))
+        }
+        fn on_error(&self, phase: Phase, error: &AssemblyError) {
+            self.failures.lock().unwrap().push((phase, error.to_string()));
+        }
+    }
+
+    assembler.register(Arc::new(BrokenInit { failures: failures.clone() })).unwrap();
+    assembler.register(Arc::new(MockServiceAssembly::new("Healthy"))).unwrap();
+
+    assert!(assembler.assemble().is_err());
+    let failures = failures.lock().unwrap();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, Phase::Init);
+    assert!(failures[0].1.contains("Init failed"));
+}
+
+// ============================================================================
+// Deregistration Tests
+// ============================================================================
+
+#[test]
+fn test_deregistered_assembly_is_not_assembled() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let registry = Arc::new(Mutex::new(None));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.register(Arc::new(MockPaymentAssembly {
+        events: events.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(VersionedAssembly {
+        version: 1,
+        registry: registry.clone(),
+    })).unwrap();
+
+    assert!(assembler.deregister_by_name("MockPaymentAssembly"));
+    assert!(!assembler.deregister_by_name("MockPaymentAssembly"));
+
+    assembler.assemble().unwrap();
+
+    assert!(events.lock().unwrap().is_empty());
+    let order = assembler.initialization_order();
+    assert_eq!(order.len(), 2);
+    assert!(!order.contains(&"MockPaymentAssembly".to_string()));
+
+    let registry = registry.lock().unwrap().clone().unwrap();
+    assert!(registry.contains::<ServiceA>());
+    assert!(!registry.contains::<Box<dyn PaymentGateway>>());
+}
+
+#[test]
+fn test_deregister_after_assemble_is_noop() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    assert!(!assembler.deregister_by_name("assembly::SimpleAssembly"));
+    assert_eq!(assembler.initialization_order(), vec!["assembly::SimpleAssembly"]);
+}
+
+// ============================================================================
+// Phase Timeout Tests
+// ============================================================================
+
+#[assembly]
+struct HangingStartAssembly {}
+
+impl ServiceAssembly for HangingStartAssembly {
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        Ok(())
+    }
+
+    fn start(&self, _context: &AssemblyContext) -> Result<()> {
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_phase_timeout_reports_hung_start() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug)
+        .with_phase_timeout(std::time::Duration::from_millis(50));
+
+    assembler.register(Arc::new(HangingStartAssembly {})).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    match err {
+        AssemblyError::PhaseTimeout { assembly, phase } => {
+            assert_eq!(assembly, "assembly::HangingStartAssembly");
+            assert_eq!(phase, Phase::Start);
+        }
+        other => panic!("Unexpected error: {}", other),
+    }
+}
+
+#[test]
+fn test_phase_timeout_allows_fast_assemblies() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug)
+        .with_phase_timeout(std::time::Duration::from_secs(5));
+
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.register(Arc::new(FailingAssembly {})).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    assert!(matches!(
+        err,
+        AssemblyError::PhaseFailure {
+            phase: Phase::Init,
+            ..
+        }
+    ));
+}
+
+// ============================================================================
+// Health Check Tests
+// ============================================================================
+
+#[assembly(name = "HealthyAssembly")]
+struct HealthyAssembly {}
+
+impl ServiceAssembly for HealthyAssembly {
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[assembly(name = "UnhealthyAssembly", requires = [ServiceA])]
+struct UnhealthyAssembly {}
+
+impl ServiceAssembly for UnhealthyAssembly {
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        Ok(())
+    }
+
+    fn health_check(&self, _context: &AssemblyContext) -> Result<()> {
+        Err(AssemblyError::GeneralError("Database unreachable".to_string()))
+    }
+}
+
+#[test]
+fn test_health_check_reports_every_assembly() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(UnhealthyAssembly {})).unwrap();
+    assembler.register(Arc::new(SimpleAssembly {})).unwrap();
+    assembler.register(Arc::new(HealthyAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let results = assembler.health_check().unwrap();
+    assert_eq!(results.len(), 3);
+
+    let names: Vec<&str> = results.iter().map(|(name, _)| name.as_str()).collect();
+    let order = assembler.initialization_order();
+    assert_eq!(names, order.iter().map(String::as_str).collect::<Vec<_>>());
+
+    for (name, result) in &results {
+        if name == "UnhealthyAssembly" {
+            let err = result.as_ref().unwrap_err();
+            assert!(err.to_string().contains("Database unreachable"));
+        } else {
+            assert!(result.is_ok(), "{} should be healthy", name);
+        }
+    }
+}
+
+#[test]
+fn test_health_check_before_assemble_fails() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(HealthyAssembly {})).unwrap();
+
+    assert!(assembler.health_check().is_err());
+}
+
+#[test]
+fn test_health_check_after_shutdown_fails() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(HealthyAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+    assert_eq!(assembler.health_check().unwrap().len(), 1);
+
+    assembler.shutdown().unwrap();
+    let err = assembler.health_check().unwrap_err();
+    assert!(err.to_string().contains("after shutdown"), "Error was: {}", err);
+}
+
+#[test]
+fn test_health_check_with_no_assemblies() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.assemble().unwrap();
+    assert!(assembler.health_check().unwrap().is_empty());
+}
+
+// ============================================================================
+// Batch Registration Tests
+// ============================================================================
+
+#[assembly(name = "Core")]
+struct CoreAssembly {
+    label: &'static str,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssembly for CoreAssembly {
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        self.events.lock().unwrap().push(self.label.to_string());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_register_all_rejects_duplicate_names() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    let result = assembler.register_all(vec![
+        Arc::new(CoreAssembly {
+            label: "first",
+            events: events.clone(),
+        }),
+        Arc::new(CoreAssembly {
+            label: "second",
+            events: events.clone(),
+        }),
+    ]);
+
+    match result {
+        Err(AssemblyError::DuplicateAssembly(name)) => assert_eq!(name, "Core"),
+        other => panic!("Expected DuplicateAssembly, got {:?}", other),
+    }
+
+    // Nothing from the rejected batch is registered
+    assembler.assemble().unwrap();
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_register_all_rejects_previously_registered_name() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(CoreAssembly {
+        label: "first",
+        events: events.clone(),
+    })).unwrap();
+    let result = assembler.register_all(vec![Arc::new(CoreAssembly {
+        label: "second",
+        events: events.clone(),
+    })]);
+    assert!(matches!(result, Err(AssemblyError::DuplicateAssembly(_))));
+
+    assembler.assemble().unwrap();
+    assert_eq!(*events.lock().unwrap(), vec!["first"]);
+}
+
+#[test]
+fn test_register_all_replaces_duplicates_when_configured() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler =
+        Assembler::new(monitor, RuntimeMode::Debug).with_duplicate_policy(DuplicatePolicy::Replace);
+
+    assembler
+        .register_all(vec![
+            Arc::new(CoreAssembly {
+                label: "first",
+                events: events.clone(),
+            }),
+            Arc::new(SimpleAssembly {}),
+            Arc::new(CoreAssembly {
+                label: "second",
+                events: events.clone(),
+            }),
+        ])
+        .unwrap();
+
+    assembler.assemble().unwrap();
+    assert_eq!(*events.lock().unwrap(), vec!["second"]);
+    assert_eq!(assembler.initialization_order().len(), 2);
+}
+
+// ============================================================================
+// Registry Access Tests
+// ============================================================================
+
+#[test]
+fn test_registry_resolves_services_after_assemble() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(DatabaseAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    let registry = assembler.registry();
+    let db = registry.resolve::<Box<dyn Database>>();
+    assert_eq!(db._query(), "postgres_data");
+}
+
+#[test]
+fn test_provider_lookups_after_assemble() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(CacheAssembly {})).unwrap();
+    assembler.register(Arc::new(DatabaseAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(
+        assembler.services_provided_by("assembly::DatabaseAssembly"),
+        vec![TypeKey::new::<Box<dyn Database>>()]
+    );
+    assert_eq!(
+        assembler.provider_of(&TypeKey::new::<Box<dyn Cache>>()),
+        Some("assembly::CacheAssembly".to_string())
+    );
+    assert_eq!(
+        assembler.provider_of(&TypeKey::new::<Box<dyn Database>>()),
+        Some("assembly::DatabaseAssembly".to_string())
+    );
+    assert_eq!(assembler.provider_of(&TypeKey::new::<ServiceA>()), None);
+    assert!(assembler.services_provided_by("Unknown").is_empty());
+}
+
+struct Counter {
+    value: std::sync::atomic::AtomicUsize,
+}
+
+#[assembly(name = "Incrementing")]
+struct IncrementingAssembly {}
+
+impl ServiceAssembly for IncrementingAssembly {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        let counter = context.registry.resolve::<Counter>();
+        counter.value.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_assemblies_resolve_services_from_supplied_registry() {
+    let registry = Arc::new(ServiceRegistry::new());
+    let counter = Arc::new(Counter {
+        value: std::sync::atomic::AtomicUsize::new(0),
+    });
+    RegistryWriteHandle::new(&registry).register(counter.clone());
+
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::with_registry(monitor, RuntimeMode::Debug, registry.clone());
+    assembler.register(Arc::new(IncrementingAssembly {})).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(counter.value.load(std::sync::atomic::Ordering::SeqCst), 1);
+    assert!(Arc::ptr_eq(&registry, &assembler.registry()));
+}
+
+// ============================================================================
+// Dependency Report Tests
+// ============================================================================
+
+#[test]
+fn test_dependency_report_nests_dependents() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler
+        .register(Arc::new(
+            MockServiceAssembly::new("WebAssembly").with_requires(vec![TypeKey::new::<ServiceB>()]),
+        ))
+        .unwrap();
+    assembler
+        .register(Arc::new(
+            MockServiceAssembly::new("Consumer")
+                .provide(ServiceB)
+                .with_requires(vec![TypeKey::new::<ServiceA>()]),
+        ))
+        .unwrap();
+    assembler
+        .register(Arc::new(
+            MockServiceAssembly::new("CoreAssembly").provide(ServiceA),
+        ))
+        .unwrap();
+
+    assembler.validate().unwrap();
+    let report = assembler.dependency_report();
+    assert_eq!(report, "CoreAssembly\n  Consumer\n    WebAssembly\n");
+
+    // Reporting does not run any lifecycle phase
+    assert!(assembler.initialization_order().is_empty());
+}
+
+#[test]
+fn test_dependency_report_lists_independent_roots() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler
+        .register(Arc::new(MockServiceAssembly::new("B")))
+        .unwrap();
+    assembler
+        .register(Arc::new(MockServiceAssembly::new("A")))
+        .unwrap();
+
+    assembler.validate().unwrap();
+    assert_eq!(assembler.dependency_report(), "A\nB\n");
+}
+
+#[test]
+fn test_dependency_report_renders_the_stored_graph() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(MockServiceAssembly::new("Core").provide(ServiceA))).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Loop1")
+            .provide(ServiceB)
+            .with_requires(vec![TypeKey::new::<ServiceA>(), TypeKey::new::<ServiceC>()]),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Loop2")
+            .provide(ServiceC)
+            .with_requires(vec![TypeKey::new::<ServiceB>()]),
+    )).unwrap();
+
+    // Nothing is rendered until a graph has been built
+    assert_eq!(assembler.dependency_report(), "");
+
+    // The graph of a failed resolution is kept, and its cycle is only followed once
+    assert!(assembler.validate().is_err());
+    assert_eq!(assembler.dependency_report(), "Core\n  Loop1\n    Loop2\n");
+}
+
+#[test]
+fn test_last_graph_after_assemble() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    assert!(assembler.last_graph().is_none());
+
+    assembler.register(Arc::new(MockServiceAssembly::new("Core").provide(ServiceA))).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Middle")
+            .provide(ServiceB)
+            .with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Edge").with_requires(vec![TypeKey::new::<ServiceB>()]),
+    )).unwrap();
+    assembler.assemble().unwrap();
+
+    let graph = assembler.last_graph().unwrap();
+    assert_eq!(graph.vertices.len(), 3);
+    assert_eq!(graph.get_vertex("Edge").unwrap().edges, vec!["Middle"]);
+    assert_eq!(graph.get_vertex("Middle").unwrap().edges, vec!["Core"]);
+    assert!(graph.get_vertex("Core").unwrap().edges.is_empty());
+}
+
+#[test]
+fn test_last_graph_kept_after_cycle() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Left")
+            .provide(ServiceA)
+            .with_requires(vec![TypeKey::new::<ServiceB>()]),
+    )).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Right")
+            .provide(ServiceB)
+            .with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+    assert!(assembler.assemble().is_err());
+
+    let graph = assembler.last_graph().unwrap();
+    assert!(graph.find_cycle().is_some());
+}
+
+// ============================================================================
+// Provision Verification Tests
+// ============================================================================
+
+#[assembly(provides = [ServiceA])]
+struct ForgetfulAssembly {}
+
+impl ServiceAssembly for ForgetfulAssembly {
+    fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_unregistered_provision_is_reported() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(ForgetfulAssembly {})).unwrap();
+
+    match assembler.assemble() {
+        Err(AssemblyError::UnfulfilledProvision { assembly, type_key }) => {
+            assert_eq!(assembly, "assembly::ForgetfulAssembly");
+            assert_eq!(type_key, TypeKey::new::<ServiceA>());
+        }
+        other => panic!("Expected UnfulfilledProvision, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_provision_check_skipped_in_production() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Production);
+
+    assembler.register(Arc::new(ForgetfulAssembly {})).unwrap();
+
+    assert!(assembler.assemble().is_ok());
+}
+
+// ============================================================================
+// Collection Binding Tests
+// ============================================================================
+
+struct SqliteDatabase;
+
+impl Database for SqliteDatabase {
+    fn _query(&self) -> String {
+        "sqlite_data".to_string()
+    }
+}
+
+#[assembly(name = "Postgres", provides_collection = [Box<dyn Database>])]
+struct PostgresContributor {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssembly for PostgresContributor {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        self.events.lock().unwrap().push("postgres".to_string());
+        context
+            .registry
+            .register_collection::<Box<dyn Database>>(Arc::new(Box::new(PostgresDatabase)));
+        Ok(())
+    }
+}
+
+#[assembly(name = "Sqlite", provides_collection = [Box<dyn Database>])]
+struct SqliteContributor {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssembly for SqliteContributor {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        self.events.lock().unwrap().push("sqlite".to_string());
+        context
+            .registry
+            .register_collection::<Box<dyn Database>>(Arc::new(Box::new(SqliteDatabase)));
+        Ok(())
+    }
+}
+
+#[assembly(name = "Reporting", requires_collection = [Box<dyn Database>])]
+struct ReportingAssembly {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssembly for ReportingAssembly {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        let databases = context.registry.resolve_collection::<Box<dyn Database>>();
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("reporting:{}", databases.len()));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_collection_requirement_orders_after_every_contributor() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Production);
+
+    // Register the consumer first so ordering comes from the collection edges
+    assembler.register(Arc::new(ReportingAssembly { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(PostgresContributor { events: events.clone() })).unwrap();
+    assembler.register(Arc::new(SqliteContributor { events: events.clone() })).unwrap();
+
+    // Several contributors to a collection are not duplicate providers
+    assembler.assemble().unwrap();
+
+    let tracked = events.lock().unwrap();
+    assert_eq!(tracked.len(), 3);
+    assert_eq!(tracked[2], "reporting:2");
+    assert_eq!(assembler.registry().resolve_collection::<Box<dyn Database>>().len(), 2);
+}
+
+#[test]
+fn test_collection_requirement_without_contributor_fails() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    let consumer = ReportingAssembly {
+        events: Arc::new(Mutex::new(Vec::new())),
+    };
+    assert!(consumer.requires().is_empty());
+    assert_eq!(consumer.requires_collection(), vec![TypeKey::new::<Box<dyn Database>>()]);
+    assembler.register(Arc::new(consumer)).unwrap();
+
+    match assembler.assemble() {
+        Err(AssemblyError::MissingDependencies(missing)) => {
+            assert_eq!(
+                missing,
+                vec![("Reporting".to_string(), TypeKey::new::<Box<dyn Database>>())]
+            );
+        }
+        other => panic!("Expected MissingDependencies, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_missing_services_and_collections_are_reported_together() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(ReportingAssembly {
+        events: Arc::new(Mutex::new(Vec::new())),
+    })).unwrap();
+    assembler.register(Arc::new(
+        MockServiceAssembly::new("Checkout").with_requires(vec![TypeKey::new::<ServiceA>()]),
+    )).unwrap();
+
+    let err = assembler.assemble().unwrap_err();
+    match &err {
+        AssemblyError::MissingDependencies(missing) => {
+            assert_eq!(missing.len(), 2);
+            assert!(missing.contains(&("Reporting".to_string(), TypeKey::new::<Box<dyn Database>>())));
+            assert!(missing.contains(&("Checkout".to_string(), TypeKey::new::<ServiceA>())));
+        }
+        other => panic!("Expected MissingDependencies, got {:?}", other),
+    }
+    let msg = err.to_string();
+    assert!(msg.contains("Reporting requires"), "Message was: {}", msg);
+    assert!(msg.contains("Checkout requires"), "Message was: {}", msg);
+}
+
+// ============================================================================
+// Subtree Shutdown Tests
+// ============================================================================
+
+struct ChainAssembly {
+    name: &'static str,
+    provides: Vec<TypeKey>,
+    requires: Vec<TypeKey>,
+    register: fn(&RegistryWriteHandle),
+    shut_down: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceAssemblyBase for ChainAssembly {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn provides(&self) -> Vec<TypeKey> {
+        self.provides.clone()
+    }
+
+    fn requires(&self) -> Vec<TypeKey> {
+        self.requires.clone()
+    }
+}
+
+impl ServiceAssembly for ChainAssembly {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        (self.register)(&context.registry);
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        self.shut_down.lock().unwrap().push(self.name.to_string());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_shutdown_subtree_stops_dependents_only() {
+    let shut_down = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    // A <- B <- C
+    assembler.register(Arc::new(ChainAssembly {
+        name: "A",
+        provides: vec![TypeKey::new::<ServiceA>()],
+        requires: Vec::new(),
+        register: |registry| registry.register(Arc::new(ServiceA)),
+        shut_down: shut_down.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(ChainAssembly {
+        name: "B",
+        provides: vec![TypeKey::new::<ServiceB>()],
+        requires: vec![TypeKey::new::<ServiceA>()],
+        register: |registry| registry.register(Arc::new(ServiceB)),
+        shut_down: shut_down.clone(),
+    })).unwrap();
+    assembler.register(Arc::new(ChainAssembly {
+        name: "C",
+        provides: vec![TypeKey::new::<ServiceC>()],
+        requires: vec![TypeKey::new::<ServiceB>()],
+        register: |registry| registry.register(Arc::new(ServiceC)),
+        shut_down: shut_down.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+
+    assembler.shutdown_subtree("B").unwrap();
+
+    assert_eq!(*shut_down.lock().unwrap(), vec!["C", "B"]);
+    assert_eq!(assembler.initialization_order(), vec!["A"]);
+
+    let registry = assembler.registry();
+    assert!(registry.contains::<ServiceA>());
+    assert!(!registry.contains::<ServiceB>());
+    assert!(!registry.contains::<ServiceC>());
+}
+
+#[test]
+fn test_shutdown_subtree_with_overridden_assembly() {
+    let shut_down = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    #[assembly(name = "Db", provides = [ServiceA])]
+    struct Db {}
+    impl ServiceAssembly for Db {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    #[assembly(name = "MockDb", provides = [ServiceA], overrides = ["Db"])]
+    struct MockDb {}
+    impl ServiceAssembly for MockDb {
+        fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+            context.registry.register(Arc::new(ServiceA));
+            Ok(())
+        }
+    }
+
+    assembler.register(Arc::new(Db {})).unwrap();
+    assembler.register(Arc::new(MockDb {})).unwrap();
+    assembler.register(Arc::new(ChainAssembly {
+        name: "Web",
+        provides: vec![TypeKey::new::<ServiceB>()],
+        requires: vec![TypeKey::new::<ServiceA>()],
+        register: |registry| registry.register(Arc::new(ServiceB)),
+        shut_down: shut_down.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+
+    // The overridden "Db" is registered but not running
+    assembler.shutdown_subtree("MockDb").unwrap();
+
+    assert_eq!(*shut_down.lock().unwrap(), vec!["Web"]);
+    assert!(assembler.initialization_order().is_empty());
+}
+
+#[test]
+fn test_shutdown_subtree_reuses_assembled_graph() {
+    let shut_down = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(CapturingMonitor::new());
+    let assembler = Assembler::new(monitor.clone(), RuntimeMode::Development);
+
+    // Two providers of ServiceA only warn outside Production
+    for name in ["A1", "A2"] {
+        assembler.register(Arc::new(ChainAssembly {
+            name,
+            provides: vec![TypeKey::new::<ServiceA>()],
+            requires: Vec::new(),
+            register: |registry| registry.register(Arc::new(ServiceA)),
+            shut_down: shut_down.clone(),
+        })).unwrap();
+    }
+    assembler.register(Arc::new(ChainAssembly {
+        name: "B",
+        provides: vec![TypeKey::new::<ServiceB>()],
+        requires: vec![TypeKey::new::<ServiceA>()],
+        register: |registry| registry.register(Arc::new(ServiceB)),
+        shut_down: shut_down.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+    let graph = assembler.last_graph().unwrap();
+    let warnings = monitor.messages_at_level(Level::Warn).len();
+
+    assembler.shutdown_subtree("A2").unwrap();
+
+    assert_eq!(*shut_down.lock().unwrap(), vec!["B", "A2"]);
+    assert_eq!(assembler.initialization_order(), vec!["A1"]);
+    assert!(assembler.last_graph().unwrap() == graph);
+    assert_eq!(monitor.messages_at_level(Level::Warn).len(), warnings);
+}
+
+#[test]
+fn test_shutdown_subtree_of_unknown_assembly_fails() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assert!(matches!(
+        assembler.shutdown_subtree("Missing"),
+        Err(AssemblyError::GeneralError(_))
+    ));
+}
+
+#[test]
+fn test_subtree_shutdown_and_reload_rejected_after_shutdown() {
+    let shut_down = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+
+    assembler.register(Arc::new(ChainAssembly {
+        name: "A",
+        provides: vec![TypeKey::new::<ServiceA>()],
+        requires: Vec::new(),
+        register: |registry| registry.register(Arc::new(ServiceA)),
+        shut_down: shut_down.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+    assembler.shutdown().unwrap();
+    assert_eq!(*shut_down.lock().unwrap(), vec!["A"]);
+
+    let err = assembler.shutdown_subtree("A").unwrap_err();
+    assert!(err.to_string().contains("after shutdown"), "Error was: {}", err);
+    let err = assembler
+        .reload("A", Arc::new(MockServiceAssembly::new("A").provide(ServiceA)))
+        .unwrap_err();
+    assert!(err.to_string().contains("after shutdown"), "Error was: {}", err);
+
+    // Neither call ran the lifecycle hooks again
+    assert_eq!(*shut_down.lock().unwrap(), vec!["A"]);
+}
+
+// ============================================================================
+// Dynamic Provides Tests
+// ============================================================================
+
+/// Decides during init whether to provide a cache
+struct ConfigurableAssembly {
+    enable_cache: bool,
+    cache_enabled: Mutex<bool>,
+}
+
+impl ConfigurableAssembly {
+    fn new(enable_cache: bool) -> Self {
+        Self {
+            enable_cache,
+            cache_enabled: Mutex::new(false),
+        }
+    }
+}
+
+impl ServiceAssemblyBase for ConfigurableAssembly {
+    fn name(&self) -> &str {
+        "Configurable"
+    }
+
+    fn provides(&self) -> Vec<TypeKey> {
+        vec![TypeKey::new::<ServiceA>()]
+    }
+
+    fn dynamic_provides(&self) -> Vec<TypeKey> {
+        if *self.cache_enabled.lock().unwrap() {
+            vec![TypeKey::new::<Box<dyn Cache>>()]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl ServiceAssembly for ConfigurableAssembly {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        context.registry.register(Arc::new(ServiceA));
+        if self.enable_cache {
+            let cache = Arc::new(Box::new(RedisCache) as Box<dyn Cache>);
+            context.registry.register(cache);
+            *self.cache_enabled.lock().unwrap() = true;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_dynamic_provides_are_recorded_after_init() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    assembler.register(Arc::new(ConfigurableAssembly::new(true))).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(
+        assembler.services_provided_by("Configurable"),
+        vec![TypeKey::new::<ServiceA>(), TypeKey::new::<Box<dyn Cache>>()]
+    );
+    assert_eq!(
+        assembler.provider_of(&TypeKey::new::<Box<dyn Cache>>()),
+        Some("Configurable".to_string())
+    );
+}
+
+#[test]
+fn test_dynamic_provides_may_be_empty() {
+    let monitor = Arc::new(NoopMonitor);
+    let assembler = Assembler::new(monitor, RuntimeMode::Debug);
+    assembler.register(Arc::new(ConfigurableAssembly::new(false))).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(assembler.services_provided_by("Configurable"), vec![TypeKey::new::<ServiceA>()]);
+    assert_eq!(assembler.provider_of(&TypeKey::new::<Box<dyn Cache>>()), None);
+}
+
+#[test]
+fn test_unregistered_dynamic_provide_is_reported() {
+    struct Boastful;
+    impl ServiceAssemblyBase for Boastful {
+        fn name(&self) -> &str {
+            "Boastful"
+        }
+        fn dynamic_provides(&self) -> Vec<TypeKey> {
+            vec![TypeKey::new::<ServiceB>()]
+        }
+    }
+    impl ServiceAssembly for Boastful {
+        fn init(&self, _context: &MutableAssemblyContext) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    let assembler = Assembler::new(Arc::new(NoopMonitor), RuntimeMode::Debug);
+    assembler.register(Arc::new(Boastful)).unwrap();
+
+    assert!(matches!(
+        assembler.assemble(),
+        Err(AssemblyError::UnfulfilledProvision { assembly, .. }) if assembly == "Boastful"
+    ));
+}
+
+// ============================================================================
+// Reentrancy Tests
+// ============================================================================
+
+/// Calls back into the assembler running its hooks and records what it observed
+#[assembly(name = "Reentrant", provides = [ServiceA])]
+struct ReentrantAssembly {
+    assembler: Arc<Mutex<std::sync::Weak<Assembler>>>,
+    observed: Arc<Mutex<Vec<String>>>,
+}
+
+impl ReentrantAssembly {
+    fn observe(&self, phase: &str) {
+        let assembler = self.assembler.lock().unwrap().upgrade().unwrap();
+        let mut observed = self.observed.lock().unwrap();
+        observed.push(format!("{}: {:?}", phase, assembler.initialization_order()));
+        if let Err(e) = assembler.health_check() {
+            observed.push(format!("{}: {}", phase, e));
+        }
+        if let Err(e) = assembler.register(Arc::new(MockServiceAssembly::new("Late"))) {
+            observed.push(format!("{}: {}", phase, e));
+        }
+    }
+}
+
+impl ServiceAssembly for ReentrantAssembly {
+    fn init(&self, context: &MutableAssemblyContext) -> Result<()> {
+        context.registry.register(Arc::new(ServiceA));
+        self.observe("init");
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        self.observe("shutdown");
+        Ok(())
+    }
+}
+
+#[test]
+fn test_hooks_may_query_the_assembler() {
+    let handle = Arc::new(Mutex::new(std::sync::Weak::new()));
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let assembler = Arc::new(Assembler::new(Arc::new(NoopMonitor), RuntimeMode::Debug));
+    *handle.lock().unwrap() = Arc::downgrade(&assembler);
+
+    assembler.register(Arc::new(ReentrantAssembly {
+        assembler: handle.clone(),
+        observed: observed.clone(),
+    })).unwrap();
+    assembler.assemble().unwrap();
+
+    assert_eq!(
+        *observed.lock().unwrap(),
+        vec![
+            "init: []",
+            "init: Cannot run health checks while assembling",
+            "init: Cannot register 'Late' while assembling",
+        ]
+    );
+    assert_eq!(assembler.registered_names(), vec!["Reentrant"]);
+
+    observed.lock().unwrap().clear();
+    assembler
+        .reload(
+            "Reentrant",
+            Arc::new(ReentrantAssembly { assembler: handle, observed: observed.clone() }),
+        )
+        .unwrap();
+
+    // The replacement initializes while the old instance is still running
+    assert_eq!(
+        *observed.lock().unwrap(),
+        vec![
+            "init: [\"Reentrant\"]",
+            "init: Cannot run health checks while an assembly is being reloaded",
+            "init: Cannot register 'Late' after assemble; use reload to replace a running assembly",
+            "shutdown: [\"Reentrant\"]",
+            "shutdown: Cannot run health checks while an assembly is being reloaded",
+            "shutdown: Cannot register 'Late' after assemble; use reload to replace a running assembly",
+        ]
+    );
+}
@@ -9,6 +9,8 @@
 // Contributors:
 //      Metaform Systems, Inc. - initial API and implementation
 
+#![cfg(feature = "std")]
+
 use assemblr::dag::{Graph, Vertex};
 
 // ============================================================================
@@ -58,6 +60,15 @@ fn test_duplicate_vertex_ignored() {
     assert_eq!(graph.get_value("A"), Some(&1)); // Original value preserved
 }
 
+#[test]
+fn test_add_vertex_reports_insertion() {
+    let mut graph: Graph<i32> = Graph::new();
+
+    assert!(graph.add_vertex("A".to_string(), 1));
+    assert!(!graph.add_vertex("A".to_string(), 2));
+    assert!(graph.add_vertex("B".to_string(), 3));
+}
+
 // ============================================================================
 // Edge Operations & Edge Cases
 // ============================================================================
@@ -75,6 +86,22 @@ fn test_add_edge_nonexistent_vertex() {
     assert!(vertex.edges.is_empty()); // No edges should be added
 }
 
+#[test]
+fn test_add_edge_checked_names_missing_vertex() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+
+    let err = graph.add_edge_checked("A", "Missing").unwrap_err();
+    assert!(err.contains("'Missing'"));
+    let err = graph.add_edge_checked("Ghost", "A").unwrap_err();
+    assert!(err.contains("'Ghost'"));
+    assert!(graph.get_vertex("A").unwrap().edges.is_empty());
+
+    assert_eq!(graph.add_edge_checked("A", "B"), Ok(()));
+    assert_eq!(graph.get_vertex("A").unwrap().edges, vec!["B".to_string()]);
+}
+
 #[test]
 fn test_duplicate_edge_ignored() {
     let mut graph: Graph<i32> = Graph::new();
@@ -254,6 +281,118 @@ fn test_diamond_dependency() {
     assert!(c_pos > 0 && c_pos < 3);
 }
 
+#[test]
+fn test_diamond_topo_positions() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("C".to_string(), 3);
+    graph.add_vertex("D".to_string(), 4);
+    graph.add_edge("A", "B");
+    graph.add_edge("A", "C");
+    graph.add_edge("B", "D");
+    graph.add_edge("C", "D");
+
+    let positions = graph.topological_sort().topo_positions();
+    assert_eq!(positions.len(), 4);
+    assert!(positions["A"] < positions["B"]);
+    assert!(positions["A"] < positions["D"]);
+    assert!(positions["C"] < positions["D"]);
+
+    graph.add_edge("D", "A");
+    assert!(graph.topological_sort().topo_positions().is_empty());
+}
+
+#[test]
+fn test_diamond_edge_count_and_membership() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("C".to_string(), 3);
+    graph.add_vertex("D".to_string(), 4);
+    graph.add_edge("A", "B");
+    graph.add_edge("A", "C");
+    graph.add_edge("B", "D");
+    graph.add_edge("C", "D");
+    // Duplicate edges are not counted twice
+    graph.add_edge("A", "B");
+
+    assert_eq!(graph.edge_count(), 4);
+    assert!(graph.contains_vertex("A"));
+    assert!(graph.contains_vertex("D"));
+    assert!(!graph.contains_vertex("E"));
+}
+
+#[test]
+fn test_vertex_count() {
+    let mut graph: Graph<i32> = Graph::new();
+    assert_eq!(graph.vertex_count(), 0);
+
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("A".to_string(), 3);
+    assert_eq!(graph.vertex_count(), 2);
+}
+
+#[test]
+fn test_sort_ignores_inconsistent_edges() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_edge("A", "B");
+
+    // Edges pushed directly may duplicate targets or reference vertices that do not exist
+    let edges = &mut graph.vertices.get_mut("A").unwrap().edges;
+    edges.push("Missing".to_string());
+    edges.push("B".to_string());
+
+    let result = graph.topological_sort();
+    assert!(!result.has_cycle);
+    assert_eq!(result.sorted_order, vec!["A", "B"]);
+}
+
+#[test]
+fn test_subgraph_excludes_unreachable_vertices() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("C".to_string(), 3);
+    graph.add_vertex("D".to_string(), 4);
+    graph.add_edge("A", "B");
+    graph.add_edge("C", "B");
+    graph.add_edge("D", "A");
+
+    let subgraph = graph.subgraph(&["A", "Missing"]);
+    assert_eq!(subgraph.vertex_count(), 2);
+    assert_eq!(subgraph.get_value("A"), Some(&1));
+    assert_eq!(subgraph.get_vertex("A").unwrap().edges, vec!["B"]);
+    assert!(!subgraph.contains_vertex("C"));
+    assert!(!subgraph.contains_vertex("D"));
+    assert!(graph.subgraph(&[]).vertices.is_empty());
+}
+
+#[test]
+fn test_diamond_descendants_count() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("C".to_string(), 3);
+    graph.add_vertex("D".to_string(), 4);
+    graph.add_edge("A", "B");
+    graph.add_edge("A", "C");
+    graph.add_edge("B", "D");
+    graph.add_edge("C", "D");
+
+    // D is reachable through both B and C but counted once
+    assert_eq!(graph.descendants_count("A"), 3);
+    assert_eq!(graph.descendants_count("B"), 1);
+    assert_eq!(graph.descendants_count("D"), 0);
+    assert_eq!(graph.descendants_count("Missing"), 0);
+
+    graph.add_edge("D", "A");
+    assert_eq!(graph.descendants_count("A"), 3);
+}
+
 #[test]
 fn test_multiple_independent_components() {
     let mut graph: Graph<i32> = Graph::new();
@@ -325,6 +464,72 @@ fn test_complex_graph_topological_sort() {
     assert_eq!(result.sorted_order[5], "V5");
 }
 
+#[test]
+fn test_topological_sort_values_on_chain() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("C".to_string(), 3);
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_edge("A", "B");
+    graph.add_edge("B", "C");
+
+    assert_eq!(graph.topological_sort_values(), Ok(vec![&1, &2, &3]));
+}
+
+#[test]
+fn test_topological_sort_values_reports_cycle() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_edge("A", "B");
+    graph.add_edge("B", "A");
+
+    let cycle = graph.topological_sort_values().unwrap_err();
+    assert!(cycle.contains(&"A".to_string()));
+    assert!(cycle.contains(&"B".to_string()));
+}
+
+#[test]
+fn test_sort_independent_of_insertion_order() {
+    fn build(vertices: &[&str], edges: &[(&str, &str)]) -> Graph<i32> {
+        let mut graph: Graph<i32> = Graph::new();
+        for id in vertices {
+            graph.add_vertex(id.to_string(), 0);
+        }
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    let edges = [("A", "D"), ("A", "B"), ("A", "C"), ("B", "E"), ("C", "E"), ("D", "E")];
+    let mut reversed = edges;
+    reversed.reverse();
+
+    let first = build(&["A", "B", "C", "D", "E"], &edges);
+    let second = build(&["E", "D", "C", "B", "A"], &reversed);
+
+    assert_eq!(
+        first.topological_sort().sorted_order,
+        second.topological_sort().sorted_order
+    );
+    assert_eq!(first.topological_sort().sorted_order, vec!["A", "B", "C", "D", "E"]);
+}
+
+#[test]
+fn test_sorted_edges_keeps_insertion_order_in_edges() {
+    let mut graph: Graph<i32> = Graph::new();
+    for id in ["A", "B", "C"] {
+        graph.add_vertex(id.to_string(), 0);
+    }
+    graph.add_edge("A", "C");
+    graph.add_edge("A", "B");
+
+    let vertex = graph.get_vertex("A").unwrap();
+    assert_eq!(vertex.edges, vec!["C", "B"]);
+    assert_eq!(vertex.sorted_edges(), vec!["B", "C"]);
+}
+
 // ============================================================================
 // Cycle Detection
 // ============================================================================
@@ -412,6 +617,39 @@ fn test_multiple_cycles() {
     assert!(!result.cycle_path.is_empty());
 }
 
+#[test]
+fn test_find_cycle_returns_closed_loop() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("C".to_string(), 3);
+
+    graph.add_edge("A", "B");
+    graph.add_edge("B", "C");
+    graph.add_edge("C", "A");
+
+    let cycle = graph.find_cycle().expect("cycle not found");
+    assert_eq!(cycle.len(), 4);
+    assert_eq!(cycle.first(), cycle.last());
+    for pair in cycle.windows(2) {
+        assert!(graph.get_vertex(&pair[0]).unwrap().edges.contains(&pair[1]));
+    }
+}
+
+#[test]
+fn test_find_cycle_on_acyclic_graph() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("C".to_string(), 3);
+
+    graph.add_edge("A", "B");
+    graph.add_edge("A", "C");
+    graph.add_edge("B", "C");
+
+    assert_eq!(graph.find_cycle(), None);
+}
+
 // ============================================================================
 // Special Cases
 // ============================================================================
@@ -435,3 +673,164 @@ fn test_default_trait() {
     let graph: Graph<i32> = Graph::default();
     assert_eq!(graph.vertices.len(), 0);
 }
+
+// ============================================================================
+// Equality
+// ============================================================================
+
+fn diamond(edges: &[(&str, &str)]) -> Graph<i32> {
+    let mut graph: Graph<i32> = Graph::new();
+    for (i, id) in ["A", "B", "C", "D"].iter().enumerate() {
+        graph.add_vertex(id.to_string(), i as i32);
+    }
+    for (from, to) in edges {
+        graph.add_edge(from, to);
+    }
+    graph
+}
+
+#[test]
+fn test_graph_equality_ignores_edge_order() {
+    let first = diamond(&[("A", "B"), ("A", "C"), ("B", "D"), ("C", "D")]);
+    let second = diamond(&[("C", "D"), ("A", "C"), ("B", "D"), ("A", "B")]);
+
+    assert_eq!(first.get_vertex("A").unwrap().edges, vec!["B", "C"]);
+    assert_eq!(second.get_vertex("A").unwrap().edges, vec!["C", "B"]);
+    assert!(first == second);
+}
+
+#[test]
+fn test_graph_inequality_on_edges_and_values() {
+    let graph = diamond(&[("A", "B"), ("A", "C"), ("B", "D"), ("C", "D")]);
+
+    let missing_edge = diamond(&[("A", "B"), ("A", "C"), ("B", "D")]);
+    assert!(graph != missing_edge);
+
+    let mut changed_value = graph.clone();
+    changed_value.vertices.get_mut("D").unwrap().value = 42;
+    assert!(graph != changed_value);
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+#[test]
+fn test_to_mermaid_edges() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("C".to_string(), 3);
+    graph.add_edge("A", "B");
+    graph.add_edge("A", "C");
+    graph.add_edge("B", "C");
+
+    let mermaid = graph.to_mermaid();
+    assert!(mermaid.starts_with("graph TD\n"));
+    assert!(mermaid.contains("n0[\"A\"]"));
+    assert!(mermaid.contains("n0 --> n1"));
+    assert!(mermaid.contains("n0 --> n2"));
+    assert!(mermaid.contains("n1 --> n2"));
+    assert!(!mermaid.contains("n2 --> "));
+}
+
+#[test]
+fn test_to_mermaid_keeps_original_ids_as_labels() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("web-server".to_string(), 1);
+    graph.add_vertex("db::pool".to_string(), 2);
+    graph.add_edge("web-server", "db::pool");
+
+    let mermaid = graph.to_mermaid();
+    assert!(mermaid.contains("n0[\"db::pool\"]"));
+    assert!(mermaid.contains("n1[\"web-server\"]"));
+    assert!(mermaid.contains("n1 --> n0"));
+}
+
+#[test]
+fn test_to_mermaid_distinguishes_similar_ids() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("my-db".to_string(), 1);
+    graph.add_vertex("my_db".to_string(), 2);
+    graph.add_edge("my-db", "my_db");
+
+    let mermaid = graph.to_mermaid();
+    assert!(mermaid.contains("n0[\"my-db\"]"));
+    assert!(mermaid.contains("n1[\"my_db\"]"));
+    assert!(mermaid.contains("n0 --> n1"));
+}
+
+#[test]
+fn test_to_mermaid_does_not_emit_reserved_keywords() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("start".to_string(), 1);
+    graph.add_vertex("end".to_string(), 2);
+    graph.add_edge("start", "end");
+
+    let mermaid = graph.to_mermaid();
+    assert!(mermaid.contains("n0[\"end\"]"));
+    assert!(mermaid.contains("n1 --> n0"));
+    assert!(mermaid.lines().skip(1).all(|line| !line.trim_start().starts_with("end")));
+}
+
+// ============================================================================
+// Cycle Prevention
+// ============================================================================
+
+#[test]
+fn test_would_create_cycle_on_chain() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+    graph.add_vertex("B".to_string(), 2);
+    graph.add_vertex("C".to_string(), 3);
+    graph.add_edge("A", "B");
+    graph.add_edge("B", "C");
+
+    // Closing the loop C -> A would create a cycle
+    assert!(graph.would_create_cycle("C", "A"));
+    assert!(graph.would_create_cycle("B", "A"));
+    // Forward edges keep the graph acyclic
+    assert!(!graph.would_create_cycle("A", "C"));
+    // Graph is left untouched
+    assert!(!graph.topological_sort().has_cycle);
+}
+
+#[test]
+fn test_would_create_cycle_self_loop_and_missing_vertex() {
+    let mut graph: Graph<i32> = Graph::new();
+    graph.add_vertex("A".to_string(), 1);
+
+    assert!(graph.would_create_cycle("A", "A"));
+    assert!(!graph.would_create_cycle("A", "Missing"));
+    assert!(!graph.would_create_cycle("Missing", "A"));
+}
+
+// ============================================================================
+// Serialization
+// ============================================================================
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_graph_json_round_trip_preserves_sort() {
+    let mut graph: Graph<i32> = Graph::new();
+    for i in 0..6 {
+        graph.add_vertex(format!("V{}", i), i);
+    }
+    graph.add_edge("V0", "V1");
+    graph.add_edge("V0", "V2");
+    graph.add_edge("V1", "V3");
+    graph.add_edge("V2", "V3");
+    graph.add_edge("V2", "V4");
+    graph.add_edge("V3", "V5");
+    graph.add_edge("V4", "V5");
+
+    let json = serde_json::to_string(&graph).unwrap();
+    let restored: Graph<i32> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.get_vertex("V2").unwrap().edges, vec!["V3", "V4"]);
+    assert_eq!(restored.get_value("V4"), Some(&4));
+    assert_eq!(
+        restored.topological_sort().sorted_order,
+        graph.topological_sort().sorted_order
+    );
+}
@@ -9,8 +9,10 @@
 // Contributors:
 //      Metaform Systems, Inc. - initial API and implementation
 
-use assemblr::registry::{RegistryWriteHandle, ServiceRegistry};
-use assemblr::{register, register_trait, resolve_trait};
+use assemblr::TypeKey;
+use assemblr::registry::{BoxError, Cleanup, RegistryError, RegistryWriteHandle, ServiceRegistry};
+use assemblr::{register, register_trait, resolve_collection_trait, resolve_trait, try_resolve_trait};
+use std::any::TypeId;
 use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
 
@@ -272,6 +274,36 @@ fn test_multiple_handles() {
     assert_eq!(cache_from_h1.name, cache_from_h2.name);
 }
 
+#[test]
+fn test_get_dyn_returns_downcastable_instance() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    register!(&handle, CacheService { name: "redis".to_string() });
+
+    let stored = registry.get_dyn(&TypeId::of::<CacheService>()).expect("service not found");
+    let Ok(cache) = stored.downcast::<CacheService>() else {
+        panic!("unexpected type");
+    };
+    assert_eq!(cache.name, "redis");
+    assert!(registry.get_dyn(&TypeId::of::<ConfigService>()).is_none());
+}
+
+#[test]
+fn test_handle_tracks_only_its_own_writes() {
+    let registry = ServiceRegistry::new();
+    let earlier = RegistryWriteHandle::new(&registry);
+    register!(&earlier, CacheService { name: "redis".to_string() });
+
+    let handle = RegistryWriteHandle::new(&registry);
+    register!(&handle, ConfigService { port: 8080, host: "localhost".to_string() });
+    register!(&handle, ConfigService { port: 9090, host: "localhost".to_string() });
+
+    assert!(handle.resolve::<CacheService>().name == "redis");
+    assert_eq!(handle.was_registered_here(), vec![TypeId::of::<ConfigService>()]);
+    assert_eq!(earlier.was_registered_here(), vec![TypeId::of::<CacheService>()]);
+}
+
 #[test]
 fn test_handle_resolve_vs_registry_resolve() {
     let registry = ServiceRegistry::new();
@@ -352,6 +384,87 @@ fn test_arc_strong_count() {
     assert!(Arc::ptr_eq(&ref2, &ref3));
 }
 
+#[test]
+fn test_with_capacity_behaves_like_new() {
+    for registry in [ServiceRegistry::new(), ServiceRegistry::with_capacity(256)] {
+        let handle = RegistryWriteHandle::new(&registry);
+        register!(&handle, CacheService { name: "redis".to_string() });
+        register_trait!(&handle, dyn DatabaseService, PostgresDb);
+
+        assert_eq!(registry.resolve::<CacheService>().name, "redis");
+        assert_eq!(resolve_trait!(&registry, dyn DatabaseService).query("SELECT 1"), "Executing: SELECT 1");
+        assert!(!registry.contains::<Counter>());
+
+        registry.clear();
+        assert!(!registry.contains::<CacheService>());
+    }
+}
+
+#[test]
+fn test_resolves_stay_identical_across_later_registrations() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    register!(&handle, CacheService { name: "redis".to_string() });
+
+    let before = registry.resolve::<CacheService>();
+    register!(&handle, ConfigService { port: 8080, host: "localhost".to_string() });
+    registry.register_as::<Counter, _>(Arc::new(Counter::new()));
+
+    assert!(Arc::ptr_eq(&before, &registry.resolve::<CacheService>()));
+}
+
+#[test]
+fn test_concurrent_registrations_are_all_kept() {
+    let registry = ServiceRegistry::new();
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| register!(&RegistryWriteHandle::new(&registry), PostgresDb));
+        scope.spawn(|| register!(&RegistryWriteHandle::new(&registry), MySqlDb));
+        scope.spawn(|| register!(&RegistryWriteHandle::new(&registry), Counter::new()));
+    });
+
+    assert!(registry.contains::<PostgresDb>());
+    assert!(registry.contains::<MySqlDb>());
+    assert!(registry.contains::<Counter>());
+}
+
+#[test]
+fn test_register_once_does_not_deadlock_with_extend() {
+    let registry = Arc::new(ServiceRegistry::new());
+    let other = ServiceRegistry::new();
+    register!(&RegistryWriteHandle::new(&other), PostgresDb);
+
+    let (done, finished) = std::sync::mpsc::channel();
+    let extending = {
+        let registry = registry.clone();
+        let done = done.clone();
+        std::thread::spawn(move || {
+            for _ in 0..10_000 {
+                registry.extend(&other);
+            }
+            done.send(()).unwrap();
+        })
+    };
+    let registering = {
+        let registry = registry.clone();
+        std::thread::spawn(move || {
+            for _ in 0..10_000 {
+                let _ = registry.register_once(Arc::new(Counter::new()));
+            }
+            done.send(()).unwrap();
+        })
+    };
+
+    for _ in 0..2 {
+        finished
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .expect("register_once and extend deadlocked");
+    }
+    extending.join().unwrap();
+    registering.join().unwrap();
+    assert!(registry.contains::<Counter>());
+}
+
 // ============================================================================
 // Complex Trait Scenarios
 // ============================================================================
@@ -435,7 +548,7 @@ fn test_register_primitive_wrapper() {
     let bool_val = registry.resolve::<BoolWrapper>();
 
     assert_eq!(int_val.0, 42);
-    assert_eq!(bool_val.0, true);
+    assert!(bool_val.0);
 }
 
 #[test]
@@ -637,6 +750,49 @@ fn test_get_nonexistent_service() {
     std::panic::set_hook(default_hook);
 }
 
+#[test]
+fn test_register_once_rejects_second_registration() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    assert!(handle.register_once(Arc::new(CacheService { name: "redis".to_string() })).is_ok());
+    let err = handle
+        .register_once(Arc::new(CacheService { name: "memcached".to_string() }))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        RegistryError::AlreadyRegistered {
+            type_name: "registry::CacheService".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), "Service 'registry::CacheService' is already registered");
+    assert_eq!(registry.resolve::<CacheService>().name, "redis");
+    assert!(registry.register_once(Arc::new(CacheService { name: "other".to_string() })).is_err());
+
+    // Plain registration still overwrites
+    handle.register(Arc::new(CacheService { name: "memcached".to_string() }));
+    assert_eq!(registry.resolve::<CacheService>().name, "memcached");
+}
+
+#[test]
+fn test_try_resolve_trait_returns_none_when_unregistered() {
+    let registry = ServiceRegistry::new();
+    assert!(try_resolve_trait!(&registry, dyn DatabaseService).is_none());
+    assert!(registry.try_resolve::<CacheService>().is_none());
+
+    let handle = RegistryWriteHandle::new(&registry);
+    register_trait!(&handle, dyn DatabaseService, PostgresDb);
+    let db = try_resolve_trait!(&handle, dyn DatabaseService).unwrap();
+    assert_eq!(db.query("SELECT 1"), "Executing: SELECT 1");
+}
+
+#[test]
+#[should_panic(expected = "Trait object 'dyn registry::DatabaseService' not found in registry")]
+fn test_resolve_trait_panic_names_trait() {
+    let registry = ServiceRegistry::new();
+    resolve_trait!(&registry, dyn DatabaseService);
+}
+
 // ============================================================================
 // Concurrent/Shared Access
 // ============================================================================
@@ -683,3 +839,429 @@ fn test_service_independence() {
     // Counter should still have its state
     assert_eq!(counter.get(), 1);
 }
+
+// ============================================================================
+// Registration Observers
+// ============================================================================
+
+#[test]
+fn test_handle_observer_notified_on_register() {
+    let registry = ServiceRegistry::new();
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let observed_clone = observed.clone();
+
+    {
+        let handle = RegistryWriteHandle::with_observer(
+            &registry,
+            Arc::new(move |type_name: &str| {
+                observed_clone.lock().unwrap().push(type_name.to_string());
+            }),
+        );
+        register!(&handle, CacheService { name: "redis".to_string() });
+        register_trait!(&handle, dyn DatabaseService, PostgresDb);
+    }
+
+    let observed = observed.lock().unwrap();
+    assert_eq!(observed.len(), 2);
+    assert!(observed[0].contains("CacheService"));
+    assert!(observed[1].contains("DatabaseService"));
+    assert!(registry.contains::<CacheService>());
+}
+
+// ============================================================================
+// Type Name Lookup
+// ============================================================================
+
+#[test]
+fn test_resolve_name_by_type_key() {
+    let registry = ServiceRegistry::new();
+
+    {
+        let handle = RegistryWriteHandle::new(&registry);
+        register!(&handle, CacheService { name: "redis".to_string() });
+        register_trait!(&handle, dyn DatabaseService, PostgresDb);
+    }
+
+    let cache_name = registry.resolve_name(&TypeKey::new::<CacheService>()).unwrap();
+    assert!(cache_name.contains("CacheService"));
+
+    let db_name = registry
+        .resolve_name(&TypeKey::new::<Box<dyn DatabaseService>>())
+        .unwrap();
+    assert!(db_name.contains("DatabaseService"));
+}
+
+#[test]
+fn test_resolve_name_missing_key() {
+    let registry = ServiceRegistry::new();
+    assert!(registry.resolve_name(&TypeKey::new::<CacheService>()).is_none());
+}
+
+mod plugins {
+    pub struct Exporter;
+    pub struct Importer;
+}
+
+mod builtin {
+    pub struct Exporter;
+}
+
+#[test]
+fn test_resolve_by_name_prefix() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    register!(&handle, plugins::Importer);
+    register!(&handle, plugins::Exporter);
+    register!(&handle, builtin::Exporter);
+
+    let found = registry.resolve_by_name_prefix("registry::plugins::");
+    let names: Vec<&str> = found.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["registry::plugins::Exporter", "registry::plugins::Importer"]);
+    assert!(found[0].1.clone().downcast::<plugins::Exporter>().is_ok());
+
+    assert_eq!(registry.resolve_by_name_prefix("registry::").len(), 3);
+    assert!(registry.resolve_by_name_prefix("other::").is_empty());
+}
+
+// ============================================================================
+// Describable Services
+// ============================================================================
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct DescribableConfig {
+    port: u16,
+    host: String,
+}
+
+#[test]
+fn test_describe_all_includes_describable_services() {
+    let registry = ServiceRegistry::new();
+
+    {
+        let handle = RegistryWriteHandle::new(&registry);
+        handle.register_describable(Arc::new(DescribableConfig {
+            port: 8080,
+            host: "localhost".to_string(),
+        }));
+        register!(&handle, CacheService { name: "redis".to_string() });
+    }
+
+    let descriptions = registry.describe_all();
+    assert_eq!(descriptions.len(), 1);
+
+    let (type_name, description) = &descriptions[0];
+    assert!(type_name.contains("DescribableConfig"));
+    assert!(description.contains("port: 8080"));
+    assert!(description.contains("host: \"localhost\""));
+
+    // Describable services still resolve normally
+    assert_eq!(registry.resolve::<DescribableConfig>().port, 8080);
+}
+
+#[test]
+fn test_clear_removes_all_services() {
+    let registry = ServiceRegistry::new();
+
+    {
+        let handle = RegistryWriteHandle::new(&registry);
+        register!(&handle, CacheService { name: "redis".to_string() });
+        register_trait!(&handle, dyn DatabaseService, PostgresDb);
+    }
+
+    registry.clear();
+
+    assert!(!registry.contains::<CacheService>());
+    assert!(!registry.contains::<Box<dyn DatabaseService>>());
+}
+
+// ============================================================================
+// Shared Registry Access
+// ============================================================================
+
+#[test]
+fn test_registry_arc_shares_handle_storage() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    let shared = handle.registry_arc();
+
+    register!(&handle, CacheService { name: "redis".to_string() });
+
+    assert_eq!(shared.resolve::<CacheService>().name, "redis");
+    assert!(registry.contains::<CacheService>());
+
+    // Registrations made after extraction are visible too
+    register_trait!(&handle, dyn DatabaseService, PostgresDb);
+    assert!(shared.contains::<Box<dyn DatabaseService>>());
+}
+
+// ============================================================================
+// Fallible Factories
+// ============================================================================
+
+#[test]
+fn test_try_factory_error_is_not_cached() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    handle.register_try_factory::<CacheService, _>(|| Err("connection refused".into()));
+    let Err(err) = registry.try_resolve_factory::<CacheService>() else {
+        panic!("Expected the factory to fail");
+    };
+    assert_eq!(err.to_string(), "connection refused");
+
+    handle.register_try_factory(|| {
+        Ok(Arc::new(CacheService {
+            name: "redis".to_string(),
+        }))
+    });
+    let service = registry.try_resolve_factory::<CacheService>().unwrap();
+    assert_eq!(service.name, "redis");
+}
+
+#[test]
+fn test_try_factory_caches_success() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    let calls = Arc::new(Mutex::new(0));
+
+    let counter = calls.clone();
+    handle.register_try_factory(move || -> Result<Arc<CacheService>, BoxError> {
+        *counter.lock().unwrap() += 1;
+        Ok(Arc::new(CacheService {
+            name: "redis".to_string(),
+        }))
+    });
+
+    assert!(registry.contains::<CacheService>());
+    let first = handle.try_resolve_factory::<CacheService>().unwrap();
+    let second = registry.try_resolve_factory::<CacheService>().unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(*calls.lock().unwrap(), 1);
+
+    // Once built, the service also resolves like a regular registration
+    assert!(Arc::ptr_eq(&first, &registry.resolve::<CacheService>()));
+}
+
+#[test]
+fn test_try_resolve_factory_missing_service() {
+    let registry = ServiceRegistry::new();
+    let Err(err) = registry.try_resolve_factory::<CacheService>() else {
+        panic!("Expected the factory to fail");
+    };
+    assert!(err.to_string().contains("not found"));
+}
+
+// ============================================================================
+// Interface Views
+// ============================================================================
+
+struct DatabasePort;
+struct ReportingPort;
+
+#[test]
+fn test_register_as_shares_instance_across_interfaces() {
+    let registry = ServiceRegistry::new();
+    let db = Arc::new(PostgresDb);
+
+    registry.register_as::<DatabasePort, _>(db.clone());
+    registry.register_as::<ReportingPort, _>(db.clone());
+
+    let as_database = registry.resolve_as::<DatabasePort, PostgresDb>();
+    let as_reporting = registry.resolve_as::<ReportingPort, PostgresDb>();
+    assert!(Arc::ptr_eq(&as_database, &db));
+    assert!(Arc::ptr_eq(&as_reporting, &db));
+    assert!(registry.contains::<DatabasePort>());
+    assert!(!registry.contains::<PostgresDb>());
+}
+
+#[test]
+fn test_handle_register_as() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    let cache = Arc::new(CacheService { name: "redis".to_string() });
+
+    handle.register(cache.clone());
+    handle.register_as::<ReportingPort, _>(cache.clone());
+
+    assert!(Arc::ptr_eq(&handle.resolve_as::<ReportingPort, CacheService>(), &cache));
+    assert!(Arc::ptr_eq(&registry.resolve::<CacheService>(), &cache));
+}
+
+#[test]
+#[should_panic(expected = "not found in registry")]
+fn test_resolve_as_wrong_concrete_type_panics() {
+    let registry = ServiceRegistry::new();
+    registry.register_as::<DatabasePort, _>(Arc::new(PostgresDb));
+
+    registry.resolve_as::<DatabasePort, MySqlDb>();
+}
+
+// ============================================================================
+// Extending Registries
+// ============================================================================
+
+#[test]
+fn test_extend_copies_services_from_other_registry() {
+    let main = ServiceRegistry::new();
+    register!(&RegistryWriteHandle::new(&main), CacheService { name: "redis".to_string() });
+
+    let subsystem = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&subsystem);
+    register!(&handle, ConfigService { port: 8080, host: "localhost".to_string() });
+    register_trait!(&handle, dyn DatabaseService, PostgresDb);
+
+    main.extend(&subsystem);
+
+    assert_eq!(main.resolve::<CacheService>().name, "redis");
+    assert_eq!(main.resolve::<ConfigService>().port, 8080);
+    assert_eq!(resolve_trait!(main, dyn DatabaseService).query("SELECT 1"), "Executing: SELECT 1");
+
+    // Instances are shared rather than copied
+    assert!(Arc::ptr_eq(
+        &main.resolve::<ConfigService>(),
+        &subsystem.resolve::<ConfigService>()
+    ));
+}
+
+#[test]
+fn test_extend_overwrites_on_collision() {
+    let main = ServiceRegistry::new();
+    register!(&RegistryWriteHandle::new(&main), CacheService { name: "redis".to_string() });
+
+    let other = ServiceRegistry::new();
+    register!(&RegistryWriteHandle::new(&other), CacheService { name: "memcached".to_string() });
+
+    main.extend(&other);
+    main.extend(&main);
+
+    assert_eq!(main.resolve::<CacheService>().name, "memcached");
+}
+
+// ============================================================================
+// Cleanup
+// ============================================================================
+
+struct SocketService {
+    closed: Arc<Mutex<bool>>,
+}
+
+impl Cleanup for SocketService {
+    fn cleanup(&self) {
+        *self.closed.lock().unwrap() = true;
+    }
+}
+
+#[test]
+fn test_clear_with_cleanup_runs_hooks() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    let closed = Arc::new(Mutex::new(false));
+
+    handle.register_with_cleanup(Arc::new(SocketService { closed: closed.clone() }));
+    register!(&handle, CacheService { name: "redis".to_string() });
+
+    // An outstanding reference keeps the service alive, but cleanup still runs
+    let _held = registry.resolve::<SocketService>();
+    registry.clear_with_cleanup();
+
+    assert!(*closed.lock().unwrap());
+    assert!(!registry.contains::<SocketService>());
+    assert!(!registry.contains::<CacheService>());
+}
+
+#[test]
+fn test_clear_skips_cleanup_hooks() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    let closed = Arc::new(Mutex::new(false));
+
+    handle.register_with_cleanup(Arc::new(SocketService { closed: closed.clone() }));
+    registry.clear();
+
+    assert!(!*closed.lock().unwrap());
+}
+
+// ============================================================================
+// Snapshots
+// ============================================================================
+
+#[test]
+fn test_restore_discards_later_registrations() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    register!(&handle, CacheService { name: "redis".to_string() });
+    let snapshot = registry.snapshot();
+    let before = registry.resolve::<CacheService>();
+
+    register_trait!(&handle, dyn DatabaseService, PostgresDb);
+    register!(&handle, CacheService { name: "memcached".to_string() });
+
+    registry.restore(snapshot);
+
+    assert!(!registry.contains::<Box<dyn DatabaseService>>());
+    let after = registry.resolve::<CacheService>();
+    assert_eq!(after.name, "redis");
+    assert!(Arc::ptr_eq(&before, &after));
+}
+
+#[test]
+fn test_snapshot_can_be_restored_repeatedly() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+    let snapshot = registry.snapshot();
+
+    for _ in 0..2 {
+        register!(&handle, CacheService { name: "redis".to_string() });
+        assert!(registry.contains::<CacheService>());
+        registry.restore(snapshot.clone());
+        assert!(!registry.contains::<CacheService>());
+    }
+}
+
+// ============================================================================
+// Collections
+// ============================================================================
+
+#[test]
+fn test_collection_keeps_every_contribution_in_order() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    handle.register_collection(Arc::new(CacheService { name: "redis".to_string() }));
+    handle.register_collection(Arc::new(CacheService { name: "memcached".to_string() }));
+
+    let names: Vec<String> = registry
+        .resolve_collection::<CacheService>()
+        .iter()
+        .map(|cache| cache.name.clone())
+        .collect();
+    assert_eq!(names, vec!["redis", "memcached"]);
+    assert!(!registry.contains::<CacheService>());
+}
+
+#[test]
+fn test_resolve_collection_trait() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    handle.register_collection(Arc::new(Box::new(PostgresDb) as Box<dyn DatabaseService>));
+    handle.register_collection(Arc::new(Box::new(MySqlDb) as Box<dyn DatabaseService>));
+
+    // Both ServiceRegistry and RegistryWriteHandle can be queried
+    for databases in [
+        resolve_collection_trait!(&registry, dyn DatabaseService),
+        resolve_collection_trait!(&handle, dyn DatabaseService),
+    ] {
+        let results: Vec<String> = databases.iter().map(|db| db.query("SELECT 1")).collect();
+        assert_eq!(results, vec!["Executing: SELECT 1", "MySQL: SELECT 1"]);
+    }
+}
+
+#[test]
+fn test_empty_collection_resolves_to_nothing() {
+    let registry = ServiceRegistry::new();
+
+    assert!(registry.resolve_collection::<CacheService>().is_empty());
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2025 Metaform Systems, Inc
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// Contributors:
+//      Metaform Systems, Inc. - initial API and implementation
+
+// Exercises the registry built on spin locks, with or without std:
+// `cargo test --no-default-features --features spin --test registry_spin`
+#![cfg(feature = "spin")]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use assemblr::registry::{RegistryWriteHandle, ServiceRegistry};
+use assemblr::{register, resolve_trait};
+
+trait Gauge: Send + Sync {
+    fn read(&self) -> u32;
+}
+
+struct Thermometer;
+
+impl Gauge for Thermometer {
+    fn read(&self) -> u32 {
+        21
+    }
+}
+
+struct Clock {
+    ticks: u64,
+}
+
+struct Sensor;
+
+#[test]
+fn test_register_and_resolve() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    handle.register(Arc::new(Clock { ticks: 42 }));
+
+    assert_eq!(registry.resolve::<Clock>().ticks, 42);
+    assert!(Arc::ptr_eq(&registry.resolve::<Clock>(), &handle.resolve::<Clock>()));
+    assert!(!registry.contains::<Sensor>());
+}
+
+#[test]
+fn test_collections_factories_and_clear() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    handle.register_collection(Arc::new(Sensor));
+    handle.register_collection(Arc::new(Sensor));
+    handle.register_try_factory(|| Ok(Arc::new(Clock { ticks: 7 })));
+
+    assert_eq!(registry.resolve_collection::<Sensor>().len(), 2);
+    assert_eq!(registry.try_resolve_factory::<Clock>().unwrap().ticks, 7);
+    assert_eq!(handle.was_registered_here().len(), 2);
+
+    registry.clear();
+    assert!(!registry.contains::<Clock>());
+    assert!(registry.resolve_collection::<Sensor>().is_empty());
+}
+
+#[test]
+fn test_macros_expand_without_std_paths() {
+    let registry = ServiceRegistry::new();
+    let handle = RegistryWriteHandle::new(&registry);
+
+    register!(&handle, Clock { ticks: 3 });
+    assemblr::register_trait!(&handle, dyn Gauge, Thermometer);
+
+    assert_eq!(registry.resolve::<Clock>().ticks, 3);
+    assert_eq!(resolve_trait!(&registry, dyn Gauge).read(), 21);
+}
@@ -0,0 +1,96 @@
+//  Copyright (c) 2026 Metaform Systems, Inc
+//
+//  This program and the accompanying materials are made available under the
+//  terms of the Apache License, Version 2.0 which is available at
+//  https://www.apache.org/licenses/LICENSE-2.0
+//
+//  SPDX-License-Identifier: Apache-2.0
+//
+//  Contributors:
+//       Metaform Systems, Inc. - initial API and implementation
+//
+
+#![cfg(feature = "tracing")]
+
+use assemblr::assembly::{LogMonitor, TracingMonitor};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::Registry;
+
+// ============================================================================
+// Test Subscriber
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct CapturedEvent {
+    level: Level,
+    target: String,
+    message: String,
+}
+
+#[derive(Clone, Default)]
+struct CapturingLayer {
+    events: Arc<Mutex<Vec<CapturedEvent>>>,
+}
+
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{:?}", value);
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for CapturingLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor(String::new());
+        event.record(&mut visitor);
+        self.events.lock().unwrap().push(CapturedEvent {
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.0,
+        });
+    }
+}
+
+fn capture(f: impl FnOnce()) -> Vec<CapturedEvent> {
+    let layer = CapturingLayer::default();
+    let subscriber = Registry::default().with(layer.clone());
+    tracing::subscriber::with_default(subscriber, f);
+    layer.events.lock().unwrap().clone()
+}
+
+// ============================================================================
+// TracingMonitor Tests
+// ============================================================================
+
+#[test]
+fn test_info_emits_tracing_event() {
+    let events = capture(|| TracingMonitor.info("assembly started"));
+
+    assert_eq!(
+        events,
+        vec![CapturedEvent {
+            level: Level::INFO,
+            target: "assemblr".to_string(),
+            message: "assembly started".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_levels_map_to_tracing_levels() {
+    let events = capture(|| {
+        TracingMonitor.debug("d");
+        TracingMonitor.warn("w");
+        TracingMonitor.error("e");
+    });
+
+    let levels: Vec<Level> = events.iter().map(|e| e.level).collect();
+    assert_eq!(levels, vec![Level::DEBUG, Level::WARN, Level::ERROR]);
+}